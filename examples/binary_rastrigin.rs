@@ -0,0 +1,70 @@
+use genetic_algorithm::binary::{Decoder, Encoding};
+use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::crossover::Crossover;
+use genetic_algorithm::domain::Domain;
+use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::mutation::random_resetting::RandomResetting;
+use genetic_algorithm::problem::Problem;
+use genetic_algorithm::selection::Selection;
+use std::f64::consts::TAU;
+use std::sync::OnceLock;
+
+const DIMENSIONS: usize = 5;
+
+fn decoder() -> &'static Decoder {
+    static DECODER: OnceLock<Decoder> = OnceLock::new();
+    DECODER.get_or_init(|| {
+        (0..DIMENSIONS).fold(Decoder::new(Encoding::Gray), |decoder, _| {
+            decoder.real_with_precision(-5.12, 5.12, 0.001)
+        })
+    })
+}
+
+#[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+struct Rastrigin;
+impl Problem for Rastrigin {
+    // Negated Rastrigin function, in millionths
+    type Fitness = i64;
+    type Allele = bool;
+
+    fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+        let value: f64 = decoder()
+            .decode_reals(chromosome.genes())
+            .into_iter()
+            .map(|x| x * x - 10.0 * (TAU * x).cos() + 10.0)
+            .sum();
+        -(value * 1e6) as i64
+    }
+
+    fn terminate(
+        &self,
+        population: &[Chromosome<Self>],
+        generation: u32,
+        _temperature: f64,
+    ) -> bool {
+        generation == 2_000 || population.iter().any(|c| c.get_fitness() > -1_000)
+    }
+
+    fn genotype() -> Vec<Self::Allele> {
+        decoder().genotype()
+    }
+
+    fn gene_domain(_locus: usize) -> Option<Domain<Self::Allele>> {
+        Some(Domain::boolean())
+    }
+}
+
+fn main() {
+    let genetic = GeneticBuilder::new()
+        .with_population_size(200)
+        .with_mutation_rate(1.0)
+        .with_problem(Rastrigin)
+        .with_selection_strategy(Selection::TournamentWithDuplicates)
+        .with_crossover_strategy(Crossover::SinglePoint)
+        .with_custom_mutation(RandomResetting::new(0.02))
+        .build();
+
+    let result = genetic.run();
+    println!("\nFitness: {}", result.get_fitness());
+    println!("x = {:?}", decoder().decode_reals(result.genes()));
+}
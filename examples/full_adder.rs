@@ -0,0 +1,81 @@
+use genetic_algorithm::cgp::evolution_strategy::OnePlusLambda;
+use genetic_algorithm::cgp::mutation::CgpMutation;
+use genetic_algorithm::cgp::{Cgp, CgpFunction};
+use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::problem::Problem;
+use std::sync::{Arc, OnceLock};
+
+fn circuit() -> &'static Arc<Cgp<bool>> {
+    static CIRCUIT: OnceLock<Arc<Cgp<bool>>> = OnceLock::new();
+    CIRCUIT.get_or_init(|| {
+        Arc::new(Cgp::new(
+            3,
+            2,
+            1,
+            30,
+            30,
+            vec![
+                CgpFunction::new("and", 2, |a| a[0] && a[1]),
+                CgpFunction::new("or", 2, |a| a[0] || a[1]),
+                CgpFunction::new("nand", 2, |a| !(a[0] && a[1])),
+                CgpFunction::new("nor", 2, |a| !(a[0] || a[1])),
+            ],
+        ))
+    })
+}
+
+/// Evolves a one-bit full adder from AND, OR, NAND and NOR gates.
+#[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+struct FullAdder;
+impl Problem for FullAdder {
+    // Correct output bits over the truth table, out of 16
+    type Fitness = u32;
+    type Allele = usize;
+
+    fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+        let program = circuit().compile(chromosome.genes());
+        (0..8_u8)
+            .map(|row| {
+                let (a, b, carry) = (row & 1 != 0, row & 2 != 0, row & 4 != 0);
+                let total = u8::from(a) + u8::from(b) + u8::from(carry);
+                let outputs = program(&[a, b, carry]);
+                u32::from(outputs[0] == (total & 1 != 0)) + u32::from(outputs[1] == (total >= 2))
+            })
+            .sum()
+    }
+
+    fn terminate(
+        &self,
+        population: &[Chromosome<Self>],
+        generation: u32,
+        _temperature: f64,
+    ) -> bool {
+        generation == 200_000 || population.iter().any(|c| c.get_fitness() == 16)
+    }
+
+    fn genotype() -> Vec<Self::Allele> {
+        circuit().genotype()
+    }
+}
+
+fn main() {
+    let es = OnePlusLambda::new(FullAdder, CgpMutation::single_active(Arc::clone(circuit())))
+        .with_lambda(4);
+
+    let result = es.run();
+    println!("\nFitness: {}", result.get_fitness());
+
+    let cgp = circuit();
+    let active = cgp.active_nodes(result.genes());
+    for node in (0..cgp.nodes()).filter(|&n| active[n]) {
+        let genes = cgp.node_genes(result.genes(), node);
+        let function = &cgp.functions[genes[0]];
+        println!(
+            "n{} = {}{:?}",
+            cgp.inputs + node,
+            function.name,
+            &genes[1..=function.arity]
+        );
+    }
+    println!("outputs = {:?}", cgp.output_genes(result.genes()));
+}
@@ -0,0 +1,107 @@
+use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::crossover::Crossover;
+use genetic_algorithm::domain::Domain;
+use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::grammar::{self, Grammar};
+use genetic_algorithm::mutation::random_resetting::RandomResetting;
+use genetic_algorithm::problem::Problem;
+use genetic_algorithm::real::Real;
+use genetic_algorithm::selection::Selection;
+
+// Samples of x^4 + x^3 + x^2 + x
+const DATASET: &str = include_str!("data/quartic.csv");
+const GRAMMAR: &str = include_str!("data/polish.bnf");
+
+const CODONS: usize = 64;
+const MAX_WRAPS: usize = 2;
+
+#[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+struct Regression {
+    grammar: Grammar,
+    samples: Vec<(Real, Real)>,
+}
+
+impl Regression {
+    fn new() -> Self {
+        let samples = DATASET
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.split_once(','))
+            .map(|(x, y)| (Real(x.parse().unwrap()), Real(y.parse().unwrap())))
+            .collect();
+        Self {
+            grammar: Grammar::parse(GRAMMAR).unwrap(),
+            samples,
+        }
+    }
+}
+
+/// Evaluate an expression in Polish notation.
+fn eval<'a>(tokens: &mut impl Iterator<Item = &'a str>, x: f64) -> f64 {
+    match tokens.next() {
+        Some("+") => eval(tokens, x) + eval(tokens, x),
+        Some("-") => eval(tokens, x) - eval(tokens, x),
+        Some("*") => eval(tokens, x) * eval(tokens, x),
+        Some("x") => x,
+        Some(constant) => constant.parse().unwrap(),
+        None => unreachable!("the grammar only derives complete expressions"),
+    }
+}
+
+impl Problem for Regression {
+    // Negated sum of absolute errors, in millionths
+    type Fitness = i64;
+    type Allele = u8;
+
+    fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+        let Some(program) = self.grammar.phenotype(chromosome.genes(), MAX_WRAPS) else {
+            // Invalid individual, the derivation did not complete
+            return i64::MIN;
+        };
+        let error: f64 = self
+            .samples
+            .iter()
+            .map(|&(x, y)| (eval(&mut program.split_whitespace(), x.0) - y.0).abs())
+            .sum();
+        -(error * 1e6) as i64
+    }
+
+    fn terminate(
+        &self,
+        population: &[Chromosome<Self>],
+        generation: u32,
+        _temperature: f64,
+    ) -> bool {
+        generation == 1_000 || population.iter().any(|c| c.get_fitness() > -1_000)
+    }
+
+    fn genotype() -> Vec<Self::Allele> {
+        grammar::genotype(CODONS)
+    }
+
+    fn gene_domain(_locus: usize) -> Option<Domain<Self::Allele>> {
+        Some(Domain::integer(0..=255))
+    }
+}
+
+fn main() {
+    let problem = Regression::new();
+    let genetic = GeneticBuilder::new()
+        .with_population_size(500)
+        .with_mutation_rate(0.5)
+        .with_problem(problem.clone())
+        .with_selection_strategy(Selection::TournamentWithDuplicates)
+        .with_crossover_strategy(Crossover::SinglePoint)
+        .with_custom_mutation(RandomResetting::new(0.02))
+        .build();
+
+    let result = genetic.run();
+    println!("\nFitness: {}", result.get_fitness());
+    println!(
+        "Program: {}",
+        problem
+            .grammar
+            .phenotype(result.genes(), MAX_WRAPS)
+            .unwrap()
+    );
+}
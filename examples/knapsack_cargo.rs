@@ -1,4 +1,6 @@
 use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::constraint::Penalty;
+use genetic_algorithm::crossover::Crossover;
 use genetic_algorithm::genetic::GeneticBuilder;
 use genetic_algorithm::problem::Problem;
 use genetic_algorithm::selection::Selection;
@@ -29,16 +31,16 @@ impl Cargo {
 }
 
 impl Problem for Cargo {
-    type Fitness = usize;
+    type Fitness = isize;
     type Allele = u8;
 
     fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
-        {
-            if self.get_weight(&chromosome.genes) > self.weight_limit as usize {
-                return 0;
-            }
-            self.get_value(&chromosome.genes)
-        }
+        self.get_value(chromosome.genes()) as isize
+    }
+
+    fn violation(&self, chromosome: &Chromosome<Self>) -> f64 {
+        self.get_weight(chromosome.genes())
+            .saturating_sub(self.weight_limit as usize) as f64
     }
 
     fn terminate(
@@ -64,12 +66,19 @@ fn main() {
 
     let g = GeneticBuilder::new()
         .with_population_size(50)
-        .with_selection_strategy(Selection::TournamentWithDuplicates)
+        .with_selection_strategy(Selection::FeasibilityRules)
+        .with_crossover_strategy(Crossover::SinglePoint)
+        .with_penalty(Penalty::Adaptive {
+            initial: 1.0,
+            feasible_factor: 1.5,
+            infeasible_factor: 2.0,
+            window: 5,
+        })
         .with_problem(instance.clone())
         .build();
 
     let res = g.run();
     println!("\nTotal value: {:?}", res.get_fitness());
-    println!("Total weight: {:?}", instance.get_weight(&res.genes));
-    println!("Genes: {:?}", res.genes);
+    println!("Total weight: {:?}", instance.get_weight(res.genes()));
+    println!("Genes: {:?}", res.genes());
 }
@@ -1,63 +1,66 @@
 use genetic_algorithm::chromosome::Chromosome;
-use genetic_algorithm::crossover::Crossover::{OrderOne, Uniform};
 use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::permutation::Permutation;
+use genetic_algorithm::permutation::PermutationCrossover::PartiallyMapped;
+use genetic_algorithm::permutation::PermutationMutation::Swap;
 use genetic_algorithm::problem::Problem;
-use genetic_algorithm::selection::Selection::{Elitism, TournamentWithDuplicates};
+use genetic_algorithm::selection::Selection::TournamentWithDuplicates;
 use itertools::Itertools;
-use rand::prelude::SliceRandom;
-use rand::thread_rng;
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
 struct NQueens;
 impl Problem for NQueens {
     type Fitness = i32;
-    type Allele = u8;
+    type Allele = Permutation;
 
     fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+        // Queens are placed one per row and one per column by construction,
+        // only diagonals can clash
+        let columns = &chromosome.genes()[0];
         let diagonal_clashes = (0..8)
-            .cartesian_product(0..8)
-            .filter(|(i, j)| i != j)
-            .filter(|(i, j)| {
-                let dx = ((i - j) as i32).abs();
-                let dy = (chromosome.genes[*i as usize] as i32
-                    - chromosome.genes[*j as usize] as i32)
-                    .abs();
+            .tuple_combinations()
+            .filter(|&(i, j): &(usize, usize)| {
+                let dx = i.abs_diff(j);
+                let dy = columns[i].abs_diff(columns[j]);
                 dx == dy
             })
             .count();
-        let unique = chromosome.genes.iter().unique().count();
-        unique as i32 - diagonal_clashes as i32
+        -(diagonal_clashes as i32)
     }
 
     fn terminate(
         &self,
         population: &[Chromosome<Self>],
-        generation: u32,
-        temperature: f64,
+        _generation: u32,
+        _temperature: f64,
     ) -> bool {
-        population
-            .iter()
-            .max_by_key(|c| c.get_fitness())
-            .unwrap()
-            .get_fitness()
-            == 8
+        population.iter().any(|c| c.get_fitness() == 0)
     }
 
     fn genotype() -> Vec<Self::Allele> {
-        let mut chromosome = (0..8).collect_vec();
-        chromosome.shuffle(&mut thread_rng());
-
-        chromosome
+        vec![Permutation::random(8)]
     }
 }
 
 fn main() {
     let g = GeneticBuilder::new()
         .with_population_size(100)
-        .with_selection_strategy(Elitism)
-        .with_crossover_strategy(Uniform)
+        .with_selection_strategy(TournamentWithDuplicates)
+        .with_permutation_crossover(PartiallyMapped)
+        .with_permutation_mutation(Swap)
         .with_problem(NQueens)
         .build();
-    let best = g.run();
+    let (best, lineage) = g.run_with_lineage();
     println!("{:?}", best);
+
+    // Render with `dot -Tsvg nqueens_lineage.dot -o nqueens_lineage.svg`
+    let ancestry = lineage.best_ancestry();
+    let path = std::env::temp_dir().join("nqueens_lineage.dot");
+    std::fs::write(&path, ancestry.to_dot()).unwrap();
+    println!(
+        "{} ancestors of {} recorded chromosomes written to {}",
+        ancestry.len() - 1,
+        lineage.len(),
+        path.display()
+    );
 }
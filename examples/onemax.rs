@@ -12,7 +12,7 @@ impl Problem for OneMax {
     type Allele = u8;
 
     fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
-        chromosome.genes.iter().map(|&a| a as usize).sum()
+        chromosome.genes().iter().map(|&a| a as usize).sum()
     }
 
     fn terminate(
@@ -0,0 +1,47 @@
+use genetic_algorithm::bitstring::BitString;
+use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::crossover::bitwise::BitKPoint;
+use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::mutation::bit_flip::BitFlip;
+use genetic_algorithm::problem::Problem;
+use genetic_algorithm::selection::Selection;
+
+const LENGTH: usize = 1000;
+
+#[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+struct OneMax;
+impl Problem for OneMax {
+    type Fitness = usize;
+    type Allele = BitString;
+
+    fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+        chromosome.genes().iter().map(BitString::count_ones).sum()
+    }
+
+    fn terminate(
+        &self,
+        population: &[Chromosome<Self>],
+        _generation: u32,
+        _temperature: f64,
+    ) -> bool {
+        population.iter().any(|c| c.get_fitness() == LENGTH)
+    }
+
+    fn genotype() -> Vec<Self::Allele> {
+        vec![BitString::random(LENGTH)]
+    }
+}
+
+fn main() {
+    let genetic = GeneticBuilder::new()
+        .with_population_size(100)
+        .with_mutation_rate(0.5)
+        .with_problem(OneMax)
+        .with_selection_strategy(Selection::TournamentWithDuplicates)
+        .with_custom_crossover(BitKPoint::new(2))
+        .with_custom_mutation(BitFlip::new(1.0 / LENGTH as f64))
+        .build();
+
+    let result = genetic.run();
+    println!("\nFitness: {}", result.get_fitness());
+}
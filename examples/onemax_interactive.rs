@@ -13,7 +13,7 @@ impl Problem for OneMax {
     type Allele = u8;
 
     fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
-        println!("{:?}", chromosome.genes);
+        println!("{:?}", chromosome.genes());
         println!("Rate this chromosome from 0 to 10");
         let mut fitness = String::new();
         io::stdin()
@@ -29,7 +29,7 @@ impl Problem for OneMax {
         _generation: u32,
         _temperature: f64,
     ) -> bool {
-        population.iter().any(|c| c.genes.iter().sum::<u8>() == 5)
+        population.iter().any(|c| c.genes().iter().sum::<u8>() == 5)
     }
 
     fn genotype() -> Vec<Self::Allele> {
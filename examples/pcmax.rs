@@ -1,13 +1,14 @@
 use genetic_algorithm::chromosome::Chromosome;
 use genetic_algorithm::crossover::Crossover::Uniform;
+use genetic_algorithm::domain::{self, Domain};
 use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::mutation::creep::Creep;
 use genetic_algorithm::problem::Problem;
 use genetic_algorithm::selection::Selection::{
     Roulette, TournamentWithDuplicates, TournamentWithoutDuplicates,
 };
 use itertools::Itertools;
 use once_cell::sync::{Lazy, OnceCell};
-use rand::{thread_rng, Rng};
 use std::collections::BTreeMap;
 use std::iter::Iterator;
 
@@ -42,7 +43,7 @@ impl Problem for PCmax {
     fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
         *LOWER_BOUND
             - *chromosome
-                .genes
+                .genes()
                 .iter()
                 .zip(JOBS.iter())
                 .fold(BTreeMap::<u8, u32>::new(), |mut acc, (&gene, &task)| {
@@ -65,9 +66,11 @@ impl Problem for PCmax {
     }
 
     fn genotype() -> Vec<Self::Allele> {
-        JOBS.iter()
-            .map(|_| thread_rng().gen_range(0..*PARALLEL_PROCESSORS))
-            .collect_vec()
+        domain::genotype::<Self>(JOBS.len())
+    }
+
+    fn gene_domain(_locus: usize) -> Option<Domain<Self::Allele>> {
+        Some(Domain::integer(0..=i64::from(*PARALLEL_PROCESSORS) - 1))
     }
 }
 
@@ -76,6 +79,7 @@ fn main() {
         .with_population_size(100)
         .with_mutation_rate(0.1)
         .with_crossover_strategy(Uniform(0.5))
+        .with_custom_mutation(Creep::new(2, 0.05))
         .with_selection_strategy(Roulette)
         .with_problem(PCmax)
         .build();
@@ -34,7 +34,7 @@ impl Problem for Portfolio {
 
     fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
         chromosome
-            .genes
+            .genes()
             .iter()
             .map(|c| c.roi as isize * 2 - c.risk as isize)
             .sum()
@@ -64,5 +64,5 @@ fn main() {
         .build();
     let best = g.run();
     println!("Fitness: {}", best.get_fitness());
-    println!("Genes: {:?}", best.genes);
+    println!("Genes: {:?}", best.genes());
 }
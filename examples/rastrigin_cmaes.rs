@@ -0,0 +1,77 @@
+use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::cmaes::{CmaEs, Restarts};
+use genetic_algorithm::crossover::RealCrossover;
+use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::mutation::gaussian::Gaussian;
+use genetic_algorithm::problem::Problem;
+use genetic_algorithm::real::{bounded_genotype, Real};
+use genetic_algorithm::report::Report;
+use genetic_algorithm::selection::Selection;
+use std::f64::consts::PI;
+
+const DIMENSIONS: usize = 10;
+
+#[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+struct Rastrigin;
+impl Problem for Rastrigin {
+    // Negated Rastrigin function, in millionths
+    type Fitness = i64;
+    type Allele = Real;
+
+    fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+        let sum: f64 = chromosome
+            .genes()
+            .iter()
+            .map(|x| x.0 * x.0 - 10.0 * (2.0 * PI * x.0).cos() + 10.0)
+            .sum();
+        -(sum * 1e6) as i64
+    }
+
+    fn terminate(
+        &self,
+        population: &[Chromosome<Self>],
+        generation: u32,
+        _temperature: f64,
+    ) -> bool {
+        generation == 3_000 || population.iter().any(|c| c.get_fitness() > -1_000)
+    }
+
+    fn genotype() -> Vec<Self::Allele> {
+        bounded_genotype::<Self>(DIMENSIONS)
+    }
+
+    fn gene_bounds(_locus: usize) -> Option<(f64, f64)> {
+        Some((-5.12, 5.12))
+    }
+}
+
+fn summary(name: &str, report: &Report<Rastrigin>) {
+    println!(
+        "{name}: fitness {} after {} generations, {} evaluations, {} restarts, in {:.2?}",
+        report.best.get_fitness(),
+        report.generations,
+        report.evaluations,
+        report.restarts,
+        report.elapsed
+    );
+}
+
+fn main() {
+    let genetic = GeneticBuilder::new()
+        .with_population_size(100)
+        .with_mutation_rate(0.2)
+        .with_problem(Rastrigin)
+        .with_selection_strategy(Selection::TournamentWithDuplicates)
+        .with_real_crossover_strategy(RealCrossover::SimulatedBinary(15.0))
+        .with_custom_mutation(Gaussian::new(0.01, 0.2))
+        .build();
+    let ga = genetic.run_with_report();
+
+    let cmaes = CmaEs::new(Rastrigin)
+        .with_restarts(Restarts::Bipop { max_restarts: 9 })
+        .run();
+
+    println!();
+    summary("GA", &ga);
+    summary("CMA-ES", &cmaes);
+}
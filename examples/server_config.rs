@@ -0,0 +1,92 @@
+use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::genome::{Genome, GenomeCrossover, GenomeMutation};
+use genetic_algorithm::problem::Problem;
+use genetic_algorithm::real::Real;
+use genetic_algorithm::selection::Selection;
+use std::fmt::{Display, Formatter};
+
+#[derive(Genome, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Codec {
+    None,
+    Lz4,
+    Zstd,
+}
+
+#[derive(Genome, Clone, PartialEq, Eq, Hash, Debug)]
+struct ServerConfig {
+    #[genome(range = 1..=64)]
+    threads: u32,
+    #[genome(choices = [64, 128, 256, 512, 1024])]
+    cache_mb: u32,
+    #[genome(bounds = (0.0, 1.0), sigma = 0.05)]
+    gc_ratio: Real,
+    compression: Codec,
+    keep_alive: bool,
+}
+
+impl Display for ServerConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} threads, {} MB cache, gc {:.2}, {:?}, keep-alive {}",
+            self.threads, self.cache_mb, self.gc_ratio.0, self.compression, self.keep_alive
+        )
+    }
+}
+
+#[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+struct Tuning;
+impl Problem for Tuning {
+    // Simulated throughput, in requests per second
+    type Fitness = i64;
+    type Allele = ServerConfig;
+
+    fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+        let config = &chromosome.genes()[0];
+
+        // Throughput grows with threads until the 16 cores are saturated
+        let threads = f64::from(config.threads);
+        let mut throughput = 1_000.0 * threads.min(16.0) - 20.0 * (threads - 16.0).max(0.0).powi(2);
+        throughput *= 1.0 + (f64::from(config.cache_mb) / 512.0).min(1.0) * 0.5;
+        // Collecting too often or too rarely both hurt
+        throughput *= 1.0 - (config.gc_ratio.0 - 0.3).powi(2);
+        throughput *= match config.compression {
+            Codec::None => 1.0,
+            Codec::Lz4 => 1.2,
+            Codec::Zstd => 1.1,
+        };
+        if config.keep_alive {
+            throughput *= 1.1;
+        }
+        throughput as i64
+    }
+
+    fn terminate(
+        &self,
+        _population: &[Chromosome<Self>],
+        generation: u32,
+        _temperature: f64,
+    ) -> bool {
+        generation == 200
+    }
+
+    fn genotype() -> Vec<Self::Allele> {
+        vec![ServerConfig::random()]
+    }
+}
+
+fn main() {
+    let genetic = GeneticBuilder::new()
+        .with_population_size(100)
+        .with_mutation_rate(0.3)
+        .with_problem(Tuning)
+        .with_selection_strategy(Selection::TournamentWithDuplicates)
+        .with_custom_crossover(GenomeCrossover::default())
+        .with_custom_mutation(GenomeMutation::new(0.2))
+        .build();
+
+    let result = genetic.run();
+    println!("\nFitness: {}", result.get_fitness());
+    println!("Config: {}", result.genes()[0]);
+}
@@ -1,11 +1,16 @@
 use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::crossover::Crossover;
+use genetic_algorithm::domain::{self, Domain};
 use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::mutation::deletion::Deletion;
+use genetic_algorithm::mutation::insertion::Insertion;
+use genetic_algorithm::mutation::one_of::OneOf;
+use genetic_algorithm::mutation::random_resetting::RandomResetting;
 use genetic_algorithm::problem::Problem;
-use itertools::Itertools;
 
 use genetic_algorithm::selection::Selection;
 use rand::{thread_rng, Rng};
-use strsim::hamming;
+use strsim::levenshtein;
 
 const TARGET_WORD: &str = "supercalifragilisticexpialidocious";
 
@@ -16,8 +21,10 @@ impl Problem for Spelling {
     type Allele = char;
 
     fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
-        return TARGET_WORD.len()
-            - hamming(TARGET_WORD, &chromosome.genes.iter().collect::<String>()).unwrap();
+        let word = chromosome.genes().iter().collect::<String>();
+        TARGET_WORD
+            .len()
+            .saturating_sub(levenshtein(TARGET_WORD, &word))
     }
 
     fn terminate(
@@ -32,21 +39,33 @@ impl Problem for Spelling {
     }
 
     fn genotype() -> Vec<Self::Allele> {
-        (0..TARGET_WORD.len())
-            .map(|_| thread_rng().gen_range('a'..='z'))
-            .collect_vec()
+        domain::genotype::<Self>(thread_rng().gen_range(1..=2 * TARGET_WORD.len()))
+    }
+
+    fn gene_domain(_locus: usize) -> Option<Domain<Self::Allele>> {
+        Some(Domain::Categorical(('a'..='z').collect()))
     }
 }
 
 fn main() {
     let genetic = GeneticBuilder::new()
         .with_population_size(1000)
-        .with_mutation_rate(0.1)
+        .with_mutation_rate(0.3)
+        // Splicing at unrelated cut points mostly misaligns the words, so do it sparingly
+        .with_crossover_rate(0.1)
+        .with_max_length(2 * TARGET_WORD.len())
         .with_problem(Spelling)
-        .with_selection_strategy(Selection::Roulette)
+        .with_selection_strategy(Selection::TournamentWithDuplicates)
+        .with_crossover_strategy(Crossover::CutAndSplice)
+        // Insertion and deletion fix the length, resetting substitutes wrong letters in place
+        .with_custom_mutation(OneOf::new(vec![
+            Box::new(Insertion::default()),
+            Box::new(Deletion::default()),
+            Box::new(RandomResetting::new(1.0 / TARGET_WORD.len() as f64)),
+        ]))
         .build();
 
     let res = genetic.run();
     println!("{res:?}");
-    println!("\n{}", String::from_iter(res.genes));
+    println!("\n{}", String::from_iter(res.genes()));
 }
@@ -0,0 +1,54 @@
+use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::crossover::RealCrossover;
+use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::mutation::gaussian::Gaussian;
+use genetic_algorithm::problem::Problem;
+use genetic_algorithm::real::{bounded_genotype, Real};
+use genetic_algorithm::selection::Selection;
+
+const DIMENSIONS: usize = 10;
+
+#[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+struct Sphere;
+impl Problem for Sphere {
+    // Negated sum of squares, in millionths
+    type Fitness = i64;
+    type Allele = Real;
+
+    fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+        let sum: f64 = chromosome.genes().iter().map(|x| x.0 * x.0).sum();
+        -(sum * 1e6) as i64
+    }
+
+    fn terminate(
+        &self,
+        population: &[Chromosome<Self>],
+        generation: u32,
+        _temperature: f64,
+    ) -> bool {
+        generation == 5_000 || population.iter().any(|c| c.get_fitness() > -1)
+    }
+
+    fn genotype() -> Vec<Self::Allele> {
+        bounded_genotype::<Self>(DIMENSIONS)
+    }
+
+    fn gene_bounds(_locus: usize) -> Option<(f64, f64)> {
+        Some((-5.12, 5.12))
+    }
+}
+
+fn main() {
+    let genetic = GeneticBuilder::new()
+        .with_population_size(100)
+        .with_mutation_rate(0.2)
+        .with_problem(Sphere)
+        .with_selection_strategy(Selection::TournamentWithDuplicates)
+        .with_real_crossover_strategy(RealCrossover::SimulatedBinary(15.0))
+        .with_custom_mutation(Gaussian::new(0.01, 0.2))
+        .build();
+
+    let result = genetic.run();
+    println!("\nFitness: {}", result.get_fitness());
+    println!("Genes: {:?}", result.genes());
+}
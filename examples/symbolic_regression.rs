@@ -0,0 +1,123 @@
+use genetic_algorithm::chromosome::Chromosome;
+use genetic_algorithm::genetic::GeneticBuilder;
+use genetic_algorithm::gp::hoist_mutation::HoistMutation;
+use genetic_algorithm::gp::init::ramped_half_and_half;
+use genetic_algorithm::gp::parsimony::ParsimonyTournament;
+use genetic_algorithm::gp::point_mutation::PointMutation;
+use genetic_algorithm::gp::subtree_crossover::SubtreeCrossover;
+use genetic_algorithm::gp::subtree_mutation::SubtreeMutation;
+use genetic_algorithm::gp::tree::Tree;
+use genetic_algorithm::gp::{Limits, PrimitiveSet};
+use genetic_algorithm::mutation::one_of::OneOf;
+use genetic_algorithm::problem::Problem;
+use genetic_algorithm::real::Real;
+use rand::{thread_rng, Rng};
+use std::sync::{Arc, OnceLock};
+
+// Samples of x^4 + x^3 + x^2 + x
+const DATASET: &str = include_str!("data/quartic.csv");
+
+const LIMITS: Limits = Limits {
+    max_depth: 8,
+    max_size: 64,
+};
+
+fn primitives() -> &'static Arc<PrimitiveSet<Real>> {
+    static PRIMITIVES: OnceLock<Arc<PrimitiveSet<Real>>> = OnceLock::new();
+    PRIMITIVES.get_or_init(|| {
+        PrimitiveSet::<Real>::new("real")
+            .function("add", "real", &["real", "real"], |a| Real(a[0].0 + a[1].0))
+            .function("sub", "real", &["real", "real"], |a| Real(a[0].0 - a[1].0))
+            .function("mul", "real", &["real", "real"], |a| Real(a[0].0 * a[1].0))
+            // Protected division
+            .function("div", "real", &["real", "real"], |a| {
+                if a[1].0.abs() < 1e-9 {
+                    Real(1.0)
+                } else {
+                    Real(a[0].0 / a[1].0)
+                }
+            })
+            .variable("x", "real", 0)
+            .ephemeral("const", "real", || {
+                Real((thread_rng().gen_range(-1.0..1.0_f64) * 10.0).round() / 10.0)
+            })
+            .build()
+            .unwrap()
+    })
+}
+
+#[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+struct SymbolicRegression {
+    samples: Vec<(Real, Real)>,
+}
+
+impl SymbolicRegression {
+    fn from_csv(csv: &str) -> Self {
+        let samples = csv
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (x, y) = line.split_once(',').expect("expected two columns");
+                (
+                    Real(x.trim().parse().expect("invalid x")),
+                    Real(y.trim().parse().expect("invalid y")),
+                )
+            })
+            .collect();
+        Self { samples }
+    }
+}
+
+impl Problem for SymbolicRegression {
+    // Negated sum of absolute errors, in millionths
+    type Fitness = i64;
+    type Allele = Tree<Real>;
+
+    fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+        let tree = &chromosome.genes()[0];
+        let error: f64 = self
+            .samples
+            .iter()
+            .map(|&(x, y)| (tree.eval(&[x]).0 - y.0).abs())
+            .sum();
+        if error.is_finite() {
+            -(error * 1e6) as i64
+        } else {
+            i64::MIN
+        }
+    }
+
+    fn terminate(
+        &self,
+        population: &[Chromosome<Self>],
+        generation: u32,
+        _temperature: f64,
+    ) -> bool {
+        generation == 500 || population.iter().any(|c| c.get_fitness() > -1_000)
+    }
+
+    fn genotype() -> Vec<Self::Allele> {
+        vec![ramped_half_and_half(primitives(), 2, 6)]
+    }
+}
+
+fn main() {
+    let genetic = GeneticBuilder::new()
+        .with_population_size(500)
+        .with_mutation_rate(0.1)
+        .with_crossover_rate(0.9)
+        .with_problem(SymbolicRegression::from_csv(DATASET))
+        .with_custom_selection(ParsimonyTournament::new(7))
+        .with_custom_crossover(SubtreeCrossover::new(LIMITS))
+        .with_custom_mutation(OneOf::new(vec![
+            Box::new(SubtreeMutation::new(4, LIMITS)),
+            Box::new(PointMutation::new(0.1)),
+            Box::new(HoistMutation::default()),
+        ]))
+        .build();
+
+    let result = genetic.run();
+    println!("\nFitness: {}", result.get_fitness());
+    println!("Program: {}", result.genes()[0]);
+}
@@ -0,0 +1,193 @@
+//! `#[derive(Genome)]` for `genetic_algorithm::genome::Genome`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Field, Fields};
+
+#[proc_macro_derive(Genome, attributes(genome))]
+pub fn derive_genome(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let result = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => derive_struct(&input, fields.named.iter()),
+            _ => Err(syn::Error::new_spanned(
+                &input.ident,
+                "Genome can only be derived for structs with named fields",
+            )),
+        },
+        Data::Enum(data) => derive_enum(&input, data),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "Genome cannot be derived for unions",
+        )),
+    };
+    result.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// How a field is initialized and varied, from its `#[genome(...)]` attribute.
+enum Kind {
+    Range(Expr),
+    Bounds(Expr, Option<Expr>),
+    Choices(Expr),
+    /// No attribute, the field type implements `Genome` itself.
+    Nested,
+}
+
+fn field_kind(field: &Field) -> syn::Result<Kind> {
+    let mut range = None;
+    let mut bounds = None;
+    let mut sigma = None;
+    let mut choices = None;
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("genome")) {
+        attr.parse_nested_meta(|meta| {
+            let value = || -> syn::Result<Expr> { meta.value()?.parse() };
+            if meta.path.is_ident("range") {
+                range = Some(value()?);
+            } else if meta.path.is_ident("bounds") {
+                bounds = Some(value()?);
+            } else if meta.path.is_ident("sigma") {
+                sigma = Some(value()?);
+            } else if meta.path.is_ident("choices") {
+                choices = Some(value()?);
+            } else {
+                return Err(meta.error("expected `range`, `bounds`, `sigma` or `choices`"));
+            }
+            Ok(())
+        })?;
+    }
+
+    match (range, bounds, choices) {
+        (None, None, None) if sigma.is_some() => {
+            Err(syn::Error::new_spanned(field, "`sigma` requires `bounds`"))
+        }
+        (None, None, None) => Ok(Kind::Nested),
+        (Some(range), None, None) if sigma.is_none() => Ok(Kind::Range(range)),
+        (None, Some(bounds), None) => Ok(Kind::Bounds(bounds, sigma)),
+        (None, None, Some(choices)) if sigma.is_none() => Ok(Kind::Choices(choices)),
+        _ => Err(syn::Error::new_spanned(
+            field,
+            "expected a single one of `range`, `bounds` or `choices`",
+        )),
+    }
+}
+
+fn derive_struct<'a>(
+    input: &DeriveInput,
+    fields: impl Iterator<Item = &'a Field>,
+) -> syn::Result<TokenStream2> {
+    let private = quote!(::genetic_algorithm::genome::__private);
+    let genome = quote!(::genetic_algorithm::genome::Genome);
+
+    let mut random = Vec::new();
+    let mut crossover = Vec::new();
+    let mut mutate = Vec::new();
+
+    for field in fields {
+        let name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+
+        let (sample, mutation) = match field_kind(field)? {
+            Kind::Range(range) => {
+                let sample = quote!(#private::sample_range(#range));
+                (sample.clone(), quote!(self.#name = #sample;))
+            }
+            Kind::Bounds(bounds, None) => {
+                let sample = quote!(#private::sample_bounds(#bounds));
+                (sample.clone(), quote!(self.#name = #sample;))
+            }
+            Kind::Bounds(bounds, Some(sigma)) => (
+                quote!(#private::sample_bounds(#bounds)),
+                quote!(self.#name = #private::perturb(self.#name, #bounds, #sigma);),
+            ),
+            Kind::Choices(choices) => {
+                let sample = quote!(#private::choose(&#choices));
+                (sample.clone(), quote!(self.#name = #sample;))
+            }
+            Kind::Nested => {
+                random.push(quote!(#name: <#ty as #genome>::random()));
+                crossover.push(quote! {
+                    let (a, b) = #genome::crossover(&self.#name, &other.#name);
+                    son.#name = a;
+                    daughter.#name = b;
+                });
+                mutate.push(quote!(#genome::mutate(&mut self.#name, rate);));
+                continue;
+            }
+        };
+
+        random.push(quote!(#name: #sample));
+        crossover.push(quote!(#private::swap(&mut son.#name, &mut daughter.#name);));
+        mutate.push(quote! {
+            if #private::coin(rate) {
+                #mutation
+            }
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #genome for #ident #ty_generics #where_clause {
+            fn random() -> Self {
+                Self { #(#random),* }
+            }
+
+            fn crossover(&self, other: &Self) -> (Self, Self) {
+                let mut son = self.clone();
+                let mut daughter = other.clone();
+                #(#crossover)*
+                (son, daughter)
+            }
+
+            fn mutate(&mut self, rate: f64) {
+                #(#mutate)*
+            }
+        }
+    })
+}
+
+fn derive_enum(input: &DeriveInput, data: &syn::DataEnum) -> syn::Result<TokenStream2> {
+    let private = quote!(::genetic_algorithm::genome::__private);
+    let genome = quote!(::genetic_algorithm::genome::Genome);
+
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Genome cannot be derived for an enum without variants",
+        ));
+    }
+    if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "Genome can only be derived for enums with unit variants",
+        ));
+    }
+
+    let ident = &input.ident;
+    let variants = data.variants.iter().map(|v| &v.ident);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #genome for #ident #ty_generics #where_clause {
+            fn random() -> Self {
+                #private::choose(&[#(Self::#variants),*])
+            }
+
+            fn crossover(&self, other: &Self) -> (Self, Self) {
+                let mut son = self.clone();
+                let mut daughter = other.clone();
+                #private::swap(&mut son, &mut daughter);
+                (son, daughter)
+            }
+
+            fn mutate(&mut self, rate: f64) {
+                if #private::coin(rate) {
+                    *self = <Self as #genome>::random();
+                }
+            }
+        }
+    })
+}
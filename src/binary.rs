@@ -0,0 +1,232 @@
+//! Decoding binary chromosomes into integer and real parameters.
+//!
+//! A `Decoder` splits a chromosome of bits into consecutive segments, each decoded most
+//! significant bit first into a bounded integer or a real with a given precision.
+//! The bits can be read in standard binary or in reflected Gray code, where neighbouring
+//! values differ by a single bit, avoiding the Hamming cliffs of the standard encoding.
+//! The binary crossovers and mutations then apply to continuous problems.
+
+use rand::{thread_rng, Rng};
+use std::ops::RangeInclusive;
+
+#[derive(Default, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Encoding {
+    #[default]
+    Binary,
+    Gray,
+}
+
+/// Values that can be read as a bit: `bool`, and integers, set when non-zero.
+pub trait Bit {
+    fn is_set(&self) -> bool;
+}
+
+impl Bit for bool {
+    fn is_set(&self) -> bool {
+        *self
+    }
+}
+
+macro_rules! impl_bit {
+    ($($t:ty),*) => {
+        $(
+            impl Bit for $t {
+                fn is_set(&self) -> bool {
+                    *self != 0
+                }
+            }
+        )*
+    };
+}
+
+impl_bit!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl<B: Bit> Bit for &B {
+    fn is_set(&self) -> bool {
+        (*self).is_set()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Segment {
+    /// An integer in the range, with enough bits to reach every value.
+    Integer {
+        range: RangeInclusive<i64>,
+        bits: u32,
+    },
+    /// A real in `[low, high]`, on a grid of `2^bits` evenly spaced values.
+    Real { low: f64, high: f64, bits: u32 },
+}
+
+impl Segment {
+    #[must_use]
+    pub const fn bits(&self) -> u32 {
+        match self {
+            Self::Integer { bits, .. } | Self::Real { bits, .. } => *bits,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Real(f64),
+}
+
+impl Value {
+    #[must_use]
+    pub const fn as_i64(self) -> i64 {
+        match self {
+            Self::Integer(value) => value,
+            Self::Real(value) => value as i64,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_f64(self) -> f64 {
+        match self {
+            Self::Integer(value) => value as f64,
+            Self::Real(value) => value,
+        }
+    }
+}
+
+/// Layout of the parameters encoded in a binary chromosome.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Decoder {
+    encoding: Encoding,
+    segments: Vec<Segment>,
+}
+
+impl Decoder {
+    #[must_use]
+    pub const fn new(encoding: Encoding) -> Self {
+        Self {
+            encoding,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Append an integer in `range`.
+    /// When the range is not a power of two, some values are one code more likely than others.
+    ///
+    /// # Panics
+    /// Will panic if the range is empty.
+    #[must_use]
+    pub fn integer(mut self, range: RangeInclusive<i64>) -> Self {
+        assert!(!range.is_empty(), "integer range must not be empty");
+        let span = range.end().abs_diff(*range.start());
+        let bits = u64::BITS - span.leading_zeros();
+        self.segments.push(Segment::Integer { range, bits });
+        self
+    }
+
+    /// Append a real in `[low, high]` encoded on `bits` bits.
+    ///
+    /// # Panics
+    /// Will panic if `bits` is not in `1..=64` or the bounds are reversed.
+    #[must_use]
+    pub fn real(mut self, low: f64, high: f64, bits: u32) -> Self {
+        assert!((1..=64).contains(&bits), "a segment holds 1 to 64 bits");
+        assert!(low <= high, "lower bound must not exceed upper bound");
+        self.segments.push(Segment::Real { low, high, bits });
+        self
+    }
+
+    /// Append a real in `[low, high]` with the fewest bits giving a step of at most `precision`.
+    #[must_use]
+    pub fn real_with_precision(self, low: f64, high: f64, precision: f64) -> Self {
+        let steps = ((high - low) / precision).ceil().max(1.0);
+        let bits = (steps + 1.0).log2().ceil().clamp(1.0, 64.0) as u32;
+        self.real(low, high, bits)
+    }
+
+    #[must_use]
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// Number of bits of the chromosomes this decoder reads.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.segments.iter().map(|s| s.bits() as usize).sum()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Random bits for every segment, as a genotype of `bool`s.
+    #[must_use]
+    pub fn genotype(&self) -> Vec<bool> {
+        (0..self.len()).map(|_| thread_rng().gen()).collect()
+    }
+
+    /// Decode every segment, in order.
+    ///
+    /// # Panics
+    /// Will panic if there are fewer bits than `len()`.
+    pub fn decode<I>(&self, bits: I) -> Vec<Value>
+    where
+        I: IntoIterator,
+        I::Item: Bit,
+    {
+        let mut bits = bits.into_iter();
+        self.segments
+            .iter()
+            .map(|segment| {
+                let raw = (0..segment.bits()).fold(0_u64, |raw, _| {
+                    let bit = bits.next().expect("chromosome is shorter than the decoder");
+                    raw << 1 | u64::from(bit.is_set())
+                });
+                let raw = match self.encoding {
+                    Encoding::Binary => raw,
+                    Encoding::Gray => gray_decode(raw),
+                };
+                decode_segment(segment, raw)
+            })
+            .collect()
+    }
+
+    /// Decode every segment as a real, see `Value::as_f64`.
+    pub fn decode_reals<I>(&self, bits: I) -> Vec<f64>
+    where
+        I: IntoIterator,
+        I::Item: Bit,
+    {
+        self.decode(bits).into_iter().map(Value::as_f64).collect()
+    }
+}
+
+fn decode_segment(segment: &Segment, raw: u64) -> Value {
+    match segment {
+        Segment::Integer { range, bits } => {
+            let values = u128::from(range.end().abs_diff(*range.start())) + 1;
+            // Spread the 2^bits codes evenly over the values
+            let offset = (u128::from(raw) * values) >> bits;
+            Value::Integer(range.start().wrapping_add(offset as i64))
+        }
+        Segment::Real { low, high, bits } => {
+            let max = u64::MAX >> (64 - bits);
+            Value::Real(low + (high - low) * (raw as f64 / max as f64))
+        }
+    }
+}
+
+/// The reflected Gray code of `value`.
+#[must_use]
+pub const fn gray_encode(value: u64) -> u64 {
+    value ^ (value >> 1)
+}
+
+/// The value whose reflected Gray code is `gray`.
+#[must_use]
+pub const fn gray_decode(mut gray: u64) -> u64 {
+    let mut shift = 1;
+    while shift < u64::BITS {
+        gray ^= gray >> shift;
+        shift <<= 1;
+    }
+    gray
+}
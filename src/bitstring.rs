@@ -0,0 +1,205 @@
+use rand::{thread_rng, Rng};
+use std::fmt::{Display, Formatter};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A fixed-length string of bits packed into 64-bit words.
+///
+/// Used as a single allele, it stores a binary genome in an eighth of the memory of
+/// a `Vec<u8>` and lets operators such as `crossover::bitwise` and `mutation::bit_flip`
+/// work on whole words at once. Bits past `len` in the last word are always zero.
+#[derive(Clone, Default, Eq, PartialEq, Hash, Debug)]
+pub struct BitString {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitString {
+    /// A bit string of `len` zeros.
+    #[must_use]
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    /// A bit string of `len` uniformly random bits.
+    #[must_use]
+    pub fn random(len: usize) -> Self {
+        let mut bits = Self::zeros(len);
+        thread_rng().fill(bits.words.as_mut_slice());
+        bits.clear_unused();
+        bits
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The packed storage, least significant bit first.
+    #[must_use]
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// # Panics
+    /// Will panic if `index` is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "bit index out of bounds");
+        self.words[index / WORD_BITS] >> (index % WORD_BITS) & 1 == 1
+    }
+
+    /// # Panics
+    /// Will panic if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "bit index out of bounds");
+        let mask = 1 << (index % WORD_BITS);
+        if value {
+            self.words[index / WORD_BITS] |= mask;
+        } else {
+            self.words[index / WORD_BITS] &= !mask;
+        }
+    }
+
+    /// # Panics
+    /// Will panic if `index` is out of bounds.
+    pub fn flip(&mut self, index: usize) {
+        assert!(index < self.len, "bit index out of bounds");
+        self.words[index / WORD_BITS] ^= 1 << (index % WORD_BITS);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+
+    /// Indices of the set bits, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(w * WORD_BITS + bit)
+            })
+        })
+    }
+
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    #[must_use]
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    /// Number of bits set in both `self` and `other`.
+    #[must_use]
+    pub fn count_ones_and(&self, other: &Self) -> usize {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Number of positions at which `self` and `other` differ.
+    /// Every position past the end of the shorter string counts as a difference.
+    #[must_use]
+    pub fn hamming(&self, other: &Self) -> usize {
+        let shared = self.len.min(other.len);
+        self.words
+            .iter()
+            .zip(&other.words)
+            .zip(Self::range_mask(shared, 0, shared))
+            .map(|((a, b), mask)| ((a ^ b) & mask).count_ones() as usize)
+            .sum::<usize>()
+            + self.len.abs_diff(other.len)
+    }
+
+    /// Sum of `weights[i]` over the set bits `i`, such as the total weight of a knapsack selection.
+    #[must_use]
+    pub fn weighted_sum<W: Copy + std::iter::Sum<W>>(&self, weights: &[W]) -> W {
+        self.ones().map(|i| weights[i]).sum()
+    }
+
+    /// Exchange the bits of `self` and `other` at every position set in `mask`.
+    pub fn swap_masked(&mut self, other: &mut Self, mask: &[u64]) {
+        for ((a, b), m) in self.words.iter_mut().zip(other.words.iter_mut()).zip(mask) {
+            let difference = (*a ^ *b) & m;
+            *a ^= difference;
+            *b ^= difference;
+        }
+        self.clear_unused();
+        other.clear_unused();
+    }
+
+    /// Words with the bits in `start..end` set.
+    #[must_use]
+    pub fn range_mask(len: usize, start: usize, end: usize) -> Vec<u64> {
+        let mut mask = Self::zeros(len);
+        for (w, word) in mask.words.iter_mut().enumerate() {
+            let (lower, upper) = (w * WORD_BITS, (w + 1) * WORD_BITS);
+            let (from, to) = (start.clamp(lower, upper), end.clamp(lower, upper));
+            if from < to {
+                *word = (u64::MAX >> (WORD_BITS - (to - from))) << (from - lower);
+            }
+        }
+        mask.words
+    }
+
+    fn clear_unused(&mut self) {
+        let used = self.len % WORD_BITS;
+        if let (Some(last), true) = (self.words.last_mut(), used > 0) {
+            *last &= u64::MAX >> (WORD_BITS - used);
+        }
+    }
+}
+
+impl FromIterator<bool> for BitString {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let bits: Vec<bool> = iter.into_iter().collect();
+        let mut bit_string = Self::zeros(bits.len());
+        for (i, _) in bits.iter().enumerate().filter(|(_, &bit)| bit) {
+            bit_string.set(i, true);
+        }
+        bit_string
+    }
+}
+
+impl Display for BitString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.iter()
+            .try_for_each(|bit| write!(f, "{}", if bit { '1' } else { '0' }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hamming_counts_each_position_past_the_shorter_string_once() {
+        let long: BitString = (0..70).map(|i| i % 3 == 0).collect();
+        let mut short: BitString = long.iter().take(65).collect();
+        assert_eq!(long.hamming(&short), 5);
+        assert_eq!(short.hamming(&long), 5);
+
+        short.flip(2);
+        short.flip(64);
+        assert_eq!(long.hamming(&short), 7);
+        assert_eq!(short.hamming(&long), 7);
+    }
+}
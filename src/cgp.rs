@@ -0,0 +1,253 @@
+//! Cartesian genetic programming.
+//!
+//! A program is a grid of nodes encoded as a linear vector of integer genes:
+//! for every node, in column-major order, the index of its function followed by `arity`
+//! connection genes, then one gene per program output.
+//! Addresses `0..inputs` refer to the program inputs and `inputs + i` to the `i`-th node.
+//! A node may only read from the inputs and from the `levels_back` columns preceding its own,
+//! so the graph is acyclic; nodes no output depends on are inactive and cost nothing.
+//!
+//! Chromosomes are evolved with `Allele = usize`, using `CgpMutation` and usually the
+//! `OnePlusLambda` driver rather than a crossover-based `GeneticAlgorithm`.
+
+pub mod evolution_strategy;
+pub mod mutation;
+
+use rand::{thread_rng, Rng};
+use std::fmt::{Debug, Formatter};
+
+/// A node function reading the first `arity` connections of its node.
+#[derive(Clone)]
+pub struct CgpFunction<V> {
+    pub name: &'static str,
+    pub arity: usize,
+    pub eval: fn(&[V]) -> V,
+}
+
+impl<V> CgpFunction<V> {
+    #[must_use]
+    pub const fn new(name: &'static str, arity: usize, eval: fn(&[V]) -> V) -> Self {
+        Self { name, arity, eval }
+    }
+}
+
+/// Shape of the grid and the function set genomes are decoded with.
+#[derive(Clone)]
+pub struct Cgp<V> {
+    pub inputs: usize,
+    pub outputs: usize,
+    pub rows: usize,
+    pub columns: usize,
+    pub levels_back: usize,
+    /// Number of connection genes per node, the largest arity of the function set.
+    pub arity: usize,
+    pub functions: Vec<CgpFunction<V>>,
+}
+
+impl<V> Cgp<V> {
+    /// # Panics
+    /// Will panic if there is no input, the function set is empty or the grid has no node.
+    #[must_use]
+    pub fn new(
+        inputs: usize,
+        outputs: usize,
+        rows: usize,
+        columns: usize,
+        levels_back: usize,
+        functions: Vec<CgpFunction<V>>,
+    ) -> Self {
+        assert!(
+            inputs > 0,
+            "at least one input is required, nodes of the first column connect only to inputs"
+        );
+        assert!(!functions.is_empty(), "function set must not be empty");
+        assert!(rows > 0 && columns > 0, "grid must have at least one node");
+        Self {
+            arity: functions.iter().map(|f| f.arity).max().unwrap(),
+            inputs,
+            outputs,
+            rows,
+            columns,
+            levels_back: levels_back.max(1),
+            functions,
+        }
+    }
+
+    #[must_use]
+    pub const fn nodes(&self) -> usize {
+        self.rows * self.columns
+    }
+
+    /// Total number of genes of a genome.
+    #[must_use]
+    pub const fn genome_length(&self) -> usize {
+        self.nodes() * (self.arity + 1) + self.outputs
+    }
+
+    /// A random valid genome.
+    #[must_use]
+    pub fn genotype(&self) -> Vec<usize> {
+        (0..self.genome_length())
+            .map(|g| self.random_gene(g))
+            .collect()
+    }
+
+    /// A random value for gene `g`, valid for its role: a function index,
+    /// a connection within `levels_back`, or an output address.
+    #[must_use]
+    pub fn random_gene(&self, g: usize) -> usize {
+        let (count, split, offset) = self.gene_values(g);
+        let i = thread_rng().gen_range(0..count);
+        if i < split {
+            i
+        } else {
+            i + offset
+        }
+    }
+
+    /// A random value for gene `g` other than `value`, drawn like `random_gene`,
+    /// or `None` if the gene can take no other value.
+    #[must_use]
+    pub fn other_gene(&self, g: usize, value: usize) -> Option<usize> {
+        let (count, split, offset) = self.gene_values(g);
+        let current = if value < split {
+            Some(value)
+        } else {
+            value
+                .checked_sub(offset)
+                .filter(|i| (split..count).contains(i))
+        };
+
+        let others = count - usize::from(current.is_some());
+        if others == 0 {
+            return None;
+        }
+        let mut i = thread_rng().gen_range(0..others);
+        if current.is_some_and(|current| i >= current) {
+            i += 1;
+        }
+        Some(if i < split { i } else { i + offset })
+    }
+
+    /// The values gene `g` may take, as `(count, split, offset)`:
+    /// the `i`-th of `count` values is `i` below `split` and `i + offset` from `split` on.
+    fn gene_values(&self, g: usize) -> (usize, usize, usize) {
+        let node_genes = self.nodes() * (self.arity + 1);
+        if g >= node_genes {
+            let addresses = self.inputs + self.nodes();
+            return (addresses, addresses, 0);
+        }
+
+        let node = g / (self.arity + 1);
+        if g.is_multiple_of(self.arity + 1) {
+            return (self.functions.len(), self.functions.len(), 0);
+        }
+
+        // The inputs, then the nodes of the `levels_back` preceding columns
+        let column = node / self.rows;
+        let first = column.saturating_sub(self.levels_back) * self.rows;
+        let last = column * self.rows;
+        (self.inputs + last - first, self.inputs, first)
+    }
+
+    /// The genes of node `node`: its function followed by its connections.
+    #[must_use]
+    pub fn node_genes<'a>(&self, genes: &'a [usize], node: usize) -> &'a [usize] {
+        let start = node * (self.arity + 1);
+        &genes[start..start + self.arity + 1]
+    }
+
+    #[must_use]
+    pub fn output_genes<'a>(&self, genes: &'a [usize]) -> &'a [usize] {
+        &genes[self.nodes() * (self.arity + 1)..]
+    }
+
+    /// Which nodes some output depends on.
+    #[must_use]
+    pub fn active_nodes(&self, genes: &[usize]) -> Vec<bool> {
+        let mut active = vec![false; self.nodes()];
+        let mut pending = self.output_genes(genes).to_vec();
+
+        while let Some(address) = pending.pop() {
+            let Some(node) = address.checked_sub(self.inputs) else {
+                continue;
+            };
+            if active[node] {
+                continue;
+            }
+            active[node] = true;
+
+            let node_genes = self.node_genes(genes, node);
+            let arity = self.functions[node_genes[0]].arity;
+            pending.extend_from_slice(&node_genes[1..=arity]);
+        }
+
+        active
+    }
+
+    /// Whether gene `g` belongs to an active node or to an output, so mutating it may change the phenotype.
+    #[must_use]
+    pub fn is_active_gene(&self, active: &[bool], g: usize) -> bool {
+        active.get(g / (self.arity + 1)).copied().unwrap_or(true)
+    }
+}
+
+impl<V: Clone + Default + 'static> Cgp<V> {
+    /// Decode a genome into a closure evaluating the active nodes only,
+    /// mapping the program inputs to its outputs.
+    pub fn compile(&self, genes: &[usize]) -> impl Fn(&[V]) -> Vec<V> + Send + Sync + 'static {
+        let active = self.active_nodes(genes);
+        let inputs = self.inputs;
+        let steps: Vec<Step<V>> = (0..self.nodes())
+            .filter(|&node| active[node])
+            .map(|node| {
+                let node_genes = self.node_genes(genes, node);
+                let function = &self.functions[node_genes[0]];
+                Step {
+                    slot: inputs + node,
+                    eval: function.eval,
+                    sources: node_genes[1..=function.arity].to_vec(),
+                }
+            })
+            .collect();
+        let outputs = self.output_genes(genes).to_vec();
+        let size = inputs + self.nodes();
+
+        move |values: &[V]| {
+            let mut memory = vec![V::default(); size];
+            memory[..inputs].clone_from_slice(&values[..inputs]);
+
+            let mut args = Vec::new();
+            for step in &steps {
+                args.clear();
+                args.extend(step.sources.iter().map(|&s| memory[s].clone()));
+                memory[step.slot] = (step.eval)(&args);
+            }
+            outputs.iter().map(|&o| memory[o].clone()).collect()
+        }
+    }
+}
+
+/// An active node of a compiled program.
+struct Step<V> {
+    slot: usize,
+    eval: fn(&[V]) -> V,
+    sources: Vec<usize>,
+}
+
+impl<V> Debug for Cgp<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cgp")
+            .field("inputs", &self.inputs)
+            .field("outputs", &self.outputs)
+            .field("rows", &self.rows)
+            .field("columns", &self.columns)
+            .field("levels_back", &self.levels_back)
+            .field("arity", &self.arity)
+            .field(
+                "functions",
+                &self.functions.iter().map(|f| f.name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
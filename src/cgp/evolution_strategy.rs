@@ -0,0 +1,72 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use std::slice;
+
+/// The (1+λ) evolution strategy usual for CGP: every generation, λ mutants of the parent are
+/// evaluated and the best replaces the parent unless it is worse.
+/// Accepting equally fit offspring lets the search drift through the neutral, inactive genes.
+///
+/// `Problem::terminate` is given the current parent as the population.
+pub struct OnePlusLambda<T: Problem> {
+    problem: T,
+    lambda: usize,
+    mutation: Box<dyn MutationStrategy<T>>,
+}
+
+impl<T: Problem> OnePlusLambda<T> {
+    #[must_use]
+    pub fn new(problem: T, mutation: impl MutationStrategy<T> + 'static) -> Self {
+        Self {
+            problem,
+            lambda: 4,
+            mutation: Box::new(mutation),
+        }
+    }
+
+    /// Number of offspring per generation, 4 by default.
+    #[must_use]
+    pub fn with_lambda(mut self, lambda: usize) -> Self {
+        self.lambda = lambda.max(1);
+        self
+    }
+
+    pub fn run(&self) -> Chromosome<T> {
+        let mut parent = self.evaluate(Chromosome::new(T::genotype()));
+        let mut generation = 0;
+
+        loop {
+            if generation % 10_000 == 0 {
+                println!("Current best: {:?}", parent.get_fitness());
+            }
+            if self
+                .problem
+                .terminate(slice::from_ref(&parent), generation, 0.0)
+            {
+                return parent;
+            }
+
+            // The last of equally fit offspring wins, like `max_by_key`
+            let best = (0..self.lambda)
+                .map(|_| {
+                    let mut child = self.mutation.mutate(parent.clone());
+                    child.invalidate_fitness();
+                    child.age = 0;
+                    self.evaluate(child)
+                })
+                .max_by_key(Chromosome::get_fitness)
+                .unwrap();
+
+            parent.age += 1;
+            if best.get_fitness() >= parent.get_fitness() {
+                parent = best;
+            }
+            generation += 1;
+        }
+    }
+
+    fn evaluate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        chromosome.set_fitness(self.problem.fitness(&chromosome));
+        chromosome
+    }
+}
@@ -0,0 +1,74 @@
+use crate::cgp::Cgp;
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Point mutation of CGP genomes, drawing every new gene value within the range allowed for its role,
+/// and never keeping the old value when another one is possible.
+#[derive(Clone, Debug)]
+pub struct CgpMutation<T: Problem, V> {
+    _problem: PhantomData<T>,
+    cgp: Arc<Cgp<V>>,
+    gene_rate: Option<f64>,
+}
+
+impl<T: Problem<Allele = usize>, V> CgpMutation<T, V> {
+    /// Mutate every gene independently with probability `gene_rate`.
+    #[must_use]
+    pub const fn new(cgp: Arc<Cgp<V>>, gene_rate: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            cgp,
+            gene_rate: Some(gene_rate),
+        }
+    }
+
+    /// Goldman and Punch's single active mutation: mutate random genes until one of an active node
+    /// or an output has changed, so every offspring may behave differently from its parent.
+    #[must_use]
+    pub const fn single_active(cgp: Arc<Cgp<V>>) -> Self {
+        Self {
+            _problem: PhantomData,
+            cgp,
+            gene_rate: None,
+        }
+    }
+
+    fn mutate_gene(&self, genes: &mut [usize], g: usize) -> bool {
+        match self.cgp.other_gene(g, genes[g]) {
+            Some(value) => {
+                genes[g] = value;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T: Problem<Allele = usize>, V> MutationStrategy<T> for CgpMutation<T, V> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        let genes = chromosome.genes_mut();
+        match self.gene_rate {
+            Some(rate) => {
+                for g in 0..genes.len() {
+                    if thread_rng().gen_bool(rate) {
+                        self.mutate_gene(genes, g);
+                    }
+                }
+            }
+            None => {
+                let active = self.cgp.active_nodes(genes);
+                for _ in 0..genes.len() * 8 {
+                    let g = thread_rng().gen_range(0..genes.len());
+                    if self.mutate_gene(genes, g) && self.cgp.is_active_gene(&active, g) {
+                        break;
+                    }
+                }
+            }
+        }
+        chromosome
+    }
+}
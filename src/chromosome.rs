@@ -1,11 +1,19 @@
 use crate::problem::Problem;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
 
-#[derive(Hash, Eq, PartialEq, Debug, Clone)]
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone)]
 pub struct Chromosome<T: Problem + ?Sized> {
-    pub genes: Vec<T::Allele>,
+    // Identifies the individual in a `Lineage`, ignored by equality and hashing
+    id: u64,
+    genes: Vec<T::Allele>,
     fitness: Option<T::Fitness>,
-    size: usize,
+    case_errors: Option<Vec<T::Fitness>>,
+    // Stored as bits, so chromosomes stay hashable
+    violation: u64,
     pub age: u32,
 }
 
@@ -13,22 +21,117 @@ impl<T: Problem + ?Sized> Chromosome<T> {
     #[must_use]
     pub fn new(genes: Vec<T::Allele>) -> Self {
         Self {
-            size: genes.len(),
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
             fitness: None,
+            case_errors: None,
+            violation: 0.0_f64.to_bits(),
             age: 0,
             genes,
         }
     }
 
+    /// Unique identifier of this individual. Clones share it; mutations done by the engine assign a new one.
+    pub const fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub(crate) fn renew_id(&mut self) {
+        self.id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// # Panics
+    /// Will panic if the chromosome has not been evaluated since its genes last changed,
+    /// see `try_fitness`.
     pub fn get_fitness(&self) -> T::Fitness {
-        self.fitness.unwrap()
+        self.fitness
+            .expect("chromosome has not been evaluated since its genes changed")
+    }
+
+    /// The fitness, or `None` if the chromosome has not been evaluated since its genes last changed.
+    pub const fn try_fitness(&self) -> Option<T::Fitness> {
+        self.fitness
     }
 
     pub fn set_fitness(&mut self, fitness: T::Fitness) {
         self.fitness = Some(fitness);
     }
 
+    /// Forget the fitness, case errors and violation, so the chromosome is evaluated again.
+    /// Must be called whenever the genes are modified in place.
+    pub fn invalidate_fitness(&mut self) {
+        self.fitness = None;
+        self.case_errors = None;
+        self.violation = 0.0_f64.to_bits();
+    }
+
+    pub(crate) const fn is_evaluated(&self) -> bool {
+        self.fitness.is_some()
+    }
+
+    pub fn get_case_errors(&self) -> Option<&[T::Fitness]> {
+        self.case_errors.as_deref()
+    }
+
+    pub fn set_case_errors(&mut self, case_errors: Vec<T::Fitness>) {
+        self.case_errors = Some(case_errors);
+    }
+
+    /// Constraint violation reported by `Problem::violation`, 0 when feasible.
+    pub fn get_violation(&self) -> f64 {
+        f64::from_bits(self.violation)
+    }
+
+    pub fn set_violation(&mut self, violation: f64) {
+        self.violation = violation.to_bits();
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.get_violation() <= 0.0
+    }
+
+    pub fn genes(&self) -> &[T::Allele] {
+        &self.genes
+    }
+
+    /// Mutable access to the genes. The fitness is invalidated, as the genes may be modified.
+    pub fn genes_mut(&mut self) -> &mut Vec<T::Allele> {
+        self.invalidate_fitness();
+        &mut self.genes
+    }
+
+    /// Replace the genes, invalidating the fitness.
+    pub fn set_genes(&mut self, genes: Vec<T::Allele>) {
+        self.invalidate_fitness();
+        self.genes = genes;
+    }
+
+    pub fn into_genes(self) -> Vec<T::Allele> {
+        self.genes
+    }
+
     pub fn get_size(&self) -> usize {
-        self.size
+        self.genes.len()
+    }
+}
+
+impl<T: Problem> PartialEq for Chromosome<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.genes == other.genes
+            && self.fitness == other.fitness
+            && self.case_errors == other.case_errors
+            && self.violation == other.violation
+            && self.age == other.age
+    }
+}
+
+impl<T: Problem> Eq for Chromosome<T> {}
+
+impl<T: Problem> Hash for Chromosome<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.genes.hash(state);
+        self.fitness.hash(state);
+        self.case_errors.hash(state);
+        self.violation.hash(state);
+        self.age.hash(state);
     }
 }
@@ -0,0 +1,486 @@
+//! Covariance matrix adaptation evolution strategy, for continuous problems with `Allele = Real`.
+//!
+//! `CmaEs` reuses the `Problem` of a genetic algorithm: `fitness` is maximized,
+//! `terminate` is called every generation with the population sorted best first and the
+//! current step size as temperature, and `gene_bounds` confines the search, candidates
+//! outside the bounds being clamped onto them before evaluation.
+//! `genotype` provides the dimension and the initial mean of every run.
+//!
+//! The implementation follows Hansen's tutorial (arXiv:1604.00772): weighted recombination,
+//! cumulative step-size adaptation, and rank-one plus rank-μ covariance updates.
+//! A run stops on a flat fitness history, on a vanishing step size or on an ill-conditioned covariance matrix,
+//! and is then restarted according to `Restarts`.
+
+use crate::chromosome::Chromosome;
+use crate::mutation::gaussian::standard_normal;
+use crate::problem::Problem;
+use crate::real::Real;
+use crate::report::Report;
+use num::cast::AsPrimitive;
+use rand::{thread_rng, Rng};
+use std::time::Instant;
+
+/// What to do when a run stops before `Problem::terminate` is satisfied.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub enum Restarts {
+    /// Report the best chromosome found.
+    #[default]
+    None,
+    /// Restart with a population multiplied by `increase` every time (IPOP-CMA-ES).
+    Ipop { max_restarts: u32, increase: f64 },
+    /// Alternate between doubling populations and small populations with smaller step sizes,
+    /// spending a similar number of evaluations on both regimes (BIPOP-CMA-ES).
+    Bipop { max_restarts: u32 },
+}
+
+pub struct CmaEs<T: Problem<Allele = Real>> {
+    problem: T,
+    sigma: Option<f64>,
+    population_size: Option<usize>,
+    restarts: Restarts,
+    fitness_tolerance: f64,
+}
+
+impl<T: Problem<Allele = Real>> CmaEs<T> {
+    #[must_use]
+    pub const fn new(problem: T) -> Self {
+        Self {
+            problem,
+            sigma: None,
+            population_size: None,
+            restarts: Restarts::None,
+            fitness_tolerance: 1e-12,
+        }
+    }
+
+    /// Initial step size. Defaults to 0.3 times the average width of the gene bounds, or 1 without bounds.
+    #[must_use]
+    pub const fn with_sigma(mut self, sigma: f64) -> Self {
+        self.sigma = Some(sigma);
+        self
+    }
+
+    /// Number of candidates per generation, λ. Defaults to `4 + 3 ln(n)` in dimension `n`.
+    #[must_use]
+    pub const fn with_population_size(mut self, population_size: usize) -> Self {
+        self.population_size = Some(population_size);
+        self
+    }
+
+    /// Stop a run once the best fitness of its last `10 + 30 n / λ` generations spans less than
+    /// `tolerance` (TolFun). Defaults to 1e-12; fitness scaled to integers needs a tolerance in the same units.
+    #[must_use]
+    pub const fn with_fitness_tolerance(mut self, tolerance: f64) -> Self {
+        self.fitness_tolerance = tolerance;
+        self
+    }
+
+    /// # Panics
+    /// Will panic if an IPOP `increase` is not greater than 1.
+    #[must_use]
+    pub fn with_restarts(mut self, restarts: Restarts) -> Self {
+        if let Restarts::Ipop { increase, .. } = restarts {
+            assert!(increase > 1.0, "IPOP increase must be greater than 1");
+        }
+        self.restarts = restarts;
+        self
+    }
+
+    /// # Panics
+    /// Will panic if `Problem::genotype` is empty.
+    pub fn run(&self) -> Report<T> {
+        let start = Instant::now();
+        let n = T::genotype().len();
+        assert!(n > 0, "genotype must not be empty");
+
+        let sigma0 = self.sigma.unwrap_or_else(|| default_sigma::<T>(n));
+        let default_lambda = self
+            .population_size
+            .unwrap_or(4 + (3.0 * (n as f64).ln()).floor() as usize)
+            .max(2);
+
+        let mut best: Option<Chromosome<T>> = None;
+        let mut history = Vec::new();
+        let mut generations = 0;
+        let mut evaluations = 0;
+        let mut restarts = 0;
+
+        let (mut lambda, mut sigma) = (default_lambda, sigma0);
+        // BIPOP bookkeeping: evaluations spent in each regime, and the number of large restarts
+        let (mut large_budget, mut small_budget, mut large_runs) = (0, 0, 0);
+        let mut large_regime = true;
+        let large_lambda = |runs: u32| default_lambda.saturating_mul(2_usize.saturating_pow(runs));
+
+        loop {
+            let mut state = State::new(initial_mean::<T>(), sigma, lambda);
+            let mut run_history = Vec::new();
+            let mut run_evaluations = 0;
+
+            let terminated = loop {
+                let population = state.sample::<T>();
+                let population = self.evaluate(population);
+                evaluations += population.len();
+                run_evaluations += population.len();
+
+                let generation_best = &population[0];
+                if best
+                    .as_ref()
+                    .is_none_or(|b| generation_best.get_fitness() > b.get_fitness())
+                {
+                    best = Some(generation_best.clone());
+                }
+                history.push(generation_best.get_fitness());
+                run_history.push(generation_best.get_fitness());
+
+                if generations % 1000 == 0 {
+                    println!(
+                        "Current best: {:?} (sigma {:.3e}, lambda {})",
+                        best.as_ref().unwrap().get_fitness(),
+                        state.sigma,
+                        state.lambda
+                    );
+                }
+
+                if self
+                    .problem
+                    .terminate(&population, generations, state.sigma)
+                {
+                    break true;
+                }
+                generations += 1;
+
+                state.update(&population);
+                if state.should_stop(&run_history, sigma0, self.fitness_tolerance) {
+                    break false;
+                }
+            };
+
+            if terminated {
+                break;
+            }
+
+            match self.restarts {
+                Restarts::Ipop {
+                    max_restarts,
+                    increase,
+                } if restarts < max_restarts => {
+                    lambda = (((lambda as f64) * increase).ceil() as usize).max(2);
+                    sigma = sigma0;
+                }
+                Restarts::Bipop { max_restarts } if restarts < max_restarts => {
+                    if large_regime {
+                        large_budget += run_evaluations;
+                    } else {
+                        small_budget += run_evaluations;
+                    }
+
+                    large_regime = small_budget >= large_budget;
+                    if large_regime {
+                        large_runs += 1;
+                        lambda = large_lambda(large_runs);
+                        sigma = sigma0;
+                    } else {
+                        let large_lambda = large_lambda(large_runs) as f64;
+                        let u: f64 = thread_rng().gen();
+                        let ratio = 0.5 * large_lambda / default_lambda as f64;
+                        lambda = ((default_lambda as f64) * ratio.powf(u * u)).floor() as usize;
+                        lambda = lambda.max(2);
+                        sigma = sigma0 * 10_f64.powf(-2.0 * thread_rng().gen::<f64>());
+                    }
+                }
+                _ => break,
+            }
+            restarts += 1;
+        }
+
+        Report {
+            best: best.unwrap(),
+            generations,
+            evaluations,
+            restarts,
+            history,
+            shortfalls: Vec::new(),
+            elapsed: start.elapsed(),
+        }
+    }
+
+    /// Evaluate the candidates and sort them best first.
+    fn evaluate(&self, population: Vec<Chromosome<T>>) -> Vec<Chromosome<T>> {
+        let mut population = population
+            .into_iter()
+            .map(|mut c| {
+                c.set_fitness(self.problem.fitness(&c));
+                c
+            })
+            .collect::<Vec<_>>();
+        population.sort_by_key(|c| std::cmp::Reverse(c.get_fitness()));
+        population
+    }
+}
+
+fn default_sigma<T: Problem>(n: usize) -> f64 {
+    let widths = (0..n)
+        .filter_map(T::gene_bounds)
+        .map(|(low, high)| high - low)
+        .collect::<Vec<_>>();
+    if widths.is_empty() {
+        1.0
+    } else {
+        0.3 * widths.iter().sum::<f64>() / widths.len() as f64
+    }
+}
+
+fn initial_mean<T: Problem<Allele = Real>>() -> Vec<f64> {
+    T::genotype().into_iter().map(|x| x.0).collect()
+}
+
+/// Strategy parameters and state of a single run.
+struct State {
+    n: usize,
+    lambda: usize,
+    weights: Vec<f64>,
+    mueff: f64,
+    cc: f64,
+    cs: f64,
+    c1: f64,
+    cmu: f64,
+    damps: f64,
+    chi_n: f64,
+
+    mean: Vec<f64>,
+    sigma: f64,
+    pc: Vec<f64>,
+    ps: Vec<f64>,
+    /// Covariance matrix, row-major.
+    c: Vec<f64>,
+    /// Eigenvectors of `c`, as columns.
+    b: Vec<f64>,
+    /// Square roots of the eigenvalues of `c`.
+    d: Vec<f64>,
+    generation: usize,
+    eigen_generation: usize,
+}
+
+impl State {
+    fn new(mean: Vec<f64>, sigma: f64, lambda: usize) -> Self {
+        let n = mean.len();
+        let nf = n as f64;
+        let mu = lambda / 2;
+
+        let weights = (1..=mu)
+            .map(|i| ((lambda as f64 + 1.0) / 2.0).ln() - (i as f64).ln())
+            .collect::<Vec<_>>();
+        let total: f64 = weights.iter().sum();
+        let weights = weights.into_iter().map(|w| w / total).collect::<Vec<_>>();
+        let mueff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let cc = (4.0 + mueff / nf) / (nf + 4.0 + 2.0 * mueff / nf);
+        let cs = (mueff + 2.0) / (nf + mueff + 5.0);
+        let c1 = 2.0 / ((nf + 1.3).powi(2) + mueff);
+        let cmu = (1.0 - c1).min(2.0 * (mueff - 2.0 + 1.0 / mueff) / ((nf + 2.0).powi(2) + mueff));
+        let damps = 1.0 + 2.0 * (((mueff - 1.0) / (nf + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = nf.sqrt() * (1.0 - 1.0 / (4.0 * nf) + 1.0 / (21.0 * nf * nf));
+
+        Self {
+            n,
+            lambda,
+            weights,
+            mueff,
+            cc,
+            cs,
+            c1,
+            cmu,
+            damps,
+            chi_n,
+            mean,
+            sigma,
+            pc: vec![0.0; n],
+            ps: vec![0.0; n],
+            c: identity(n),
+            b: identity(n),
+            d: vec![1.0; n],
+            generation: 0,
+            eigen_generation: 0,
+        }
+    }
+
+    /// λ candidates `mean + sigma * B D z`, clamped to the gene bounds.
+    fn sample<T: Problem<Allele = Real>>(&self) -> Vec<Chromosome<T>> {
+        (0..self.lambda)
+            .map(|_| {
+                let dz = self
+                    .d
+                    .iter()
+                    .map(|d| d * standard_normal())
+                    .collect::<Vec<_>>();
+                let genes = (0..self.n)
+                    .map(|i| {
+                        let y: f64 = (0..self.n).map(|j| self.b[i * self.n + j] * dz[j]).sum();
+                        let x = self.mean[i] + self.sigma * y;
+                        Real(T::gene_bounds(i).map_or(x, |(low, high)| x.clamp(low, high)))
+                    })
+                    .collect();
+                Chromosome::new(genes)
+            })
+            .collect()
+    }
+
+    /// Move the mean towards the best candidates and adapt the step size and the covariance matrix.
+    /// `population` is sorted best first.
+    fn update<T: Problem<Allele = Real>>(&mut self, population: &[Chromosome<T>]) {
+        let n = self.n;
+        self.generation += 1;
+
+        // Steps of the selected candidates, in units of sigma, from the clamped solutions
+        let steps = population
+            .iter()
+            .take(self.weights.len())
+            .map(|c| {
+                c.genes()
+                    .iter()
+                    .zip(&self.mean)
+                    .map(|(x, m)| (x.0 - m) / self.sigma)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let yw = (0..n)
+            .map(|i| self.weights.iter().zip(&steps).map(|(w, y)| w * y[i]).sum())
+            .collect::<Vec<f64>>();
+        for (m, y) in self.mean.iter_mut().zip(&yw) {
+            *m += self.sigma * y;
+        }
+
+        // Cumulation for the step size, through C^(-1/2) = B D^-1 B^T
+        let bt_yw = (0..n)
+            .map(|j| (0..n).map(|i| self.b[i * n + j] * yw[i]).sum::<f64>() / self.d[j])
+            .collect::<Vec<_>>();
+        let norm = (self.cs * (2.0 - self.cs) * self.mueff).sqrt();
+        for i in 0..n {
+            let whitened: f64 = (0..n).map(|j| self.b[i * n + j] * bt_yw[j]).sum();
+            self.ps[i] = (1.0 - self.cs) * self.ps[i] + norm * whitened;
+        }
+        let ps_norm = self.ps.iter().map(|p| p * p).sum::<f64>().sqrt();
+        let decay = 1.0 - (1.0 - self.cs).powi(2 * self.generation as i32);
+        let hsig = ps_norm / decay.sqrt() / self.chi_n < 1.4 + 2.0 / (n as f64 + 1.0);
+
+        // Cumulation for the rank-one update
+        let norm = (self.cc * (2.0 - self.cc) * self.mueff).sqrt();
+        for (p, y) in self.pc.iter_mut().zip(&yw) {
+            *p = (1.0 - self.cc) * *p + if hsig { norm * y } else { 0.0 };
+        }
+
+        // Rank-one and rank-mu updates of the covariance matrix
+        let correction = if hsig {
+            0.0
+        } else {
+            self.c1 * self.cc * (2.0 - self.cc)
+        };
+        for i in 0..n {
+            for j in 0..=i {
+                let rank_mu: f64 = self
+                    .weights
+                    .iter()
+                    .zip(&steps)
+                    .map(|(w, y)| w * y[i] * y[j])
+                    .sum();
+                let value = (1.0 - self.c1 - self.cmu + correction) * self.c[i * n + j]
+                    + self.c1 * self.pc[i] * self.pc[j]
+                    + self.cmu * rank_mu;
+                self.c[i * n + j] = value;
+                self.c[j * n + i] = value;
+            }
+        }
+
+        self.sigma *= ((self.cs / self.damps) * (ps_norm / self.chi_n - 1.0)).exp();
+
+        // The decomposition is only refreshed every λ / (c1 + cμ) / n / 10 evaluations,
+        // bringing its O(n^3) cost down to O(n^2) per sampled candidate
+        let interval = self.lambda as f64 / (self.c1 + self.cmu) / n as f64 / 10.0;
+        let evaluations = (self.generation - self.eigen_generation) * self.lambda;
+        if evaluations as f64 > interval {
+            self.eigen_generation = self.generation;
+            let (values, vectors) = jacobi_eigen(&self.c, n);
+            self.d = values.into_iter().map(|v| v.max(1e-20).sqrt()).collect();
+            self.b = vectors;
+        }
+    }
+
+    /// Whether the run has converged or degenerated and should be restarted.
+    fn should_stop<F: AsPrimitive<f64>>(&self, history: &[F], sigma0: f64, tolerance: f64) -> bool {
+        let n = self.n;
+        if !self.sigma.is_finite() || self.mean.iter().any(|m| !m.is_finite()) {
+            return true;
+        }
+
+        let tiny_steps = (0..n).all(|i| self.sigma * self.c[i * n + i].sqrt() < 1e-12 * sigma0);
+
+        let max_d = self.d.iter().copied().fold(f64::MIN, f64::max);
+        let min_d = self.d.iter().copied().fold(f64::MAX, f64::min);
+        let ill_conditioned = (max_d / min_d).powi(2) > 1e14;
+
+        let window = 10 + (30.0 * n as f64 / self.lambda as f64).ceil() as usize;
+        let stagnated = history.len() > window && {
+            let recent = history[history.len() - window..].iter().map(|f| f.as_());
+            let (min, max) = recent.fold((f64::MAX, f64::MIN), |(min, max), f| {
+                (min.min(f), max.max(f))
+            });
+            max - min < tolerance
+        };
+
+        tiny_steps || ill_conditioned || stagnated
+    }
+}
+
+fn identity(n: usize) -> Vec<f64> {
+    (0..n * n)
+        .map(|k| if k / n == k % n { 1.0 } else { 0.0 })
+        .collect()
+}
+
+/// Eigenvalues and eigenvectors (as columns, row-major) of a symmetric matrix, by cyclic Jacobi rotations.
+fn jacobi_eigen(matrix: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut a = matrix.to_vec();
+    let mut v = identity(n);
+
+    for _ in 0..100 {
+        let off: f64 = (0..n)
+            .flat_map(|p| (p + 1..n).map(move |q| (p, q)))
+            .map(|(p, q)| a[p * n + q] * a[p * n + q])
+            .sum();
+        let diagonal: f64 = (0..n).map(|i| a[i * n + i] * a[i * n + i]).sum();
+        if off <= 1e-30 * diagonal {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                if apq == 0.0 {
+                    continue;
+                }
+                let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + theta.mul_add(theta, 1.0).sqrt());
+                let c = 1.0 / t.mul_add(t, 1.0).sqrt();
+                let s = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = c * vkp - s * vkq;
+                    v[k * n + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    ((0..n).map(|i| a[i * n + i]).collect(), v)
+}
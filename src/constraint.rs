@@ -0,0 +1,103 @@
+use crate::chromosome::Chromosome;
+use crate::problem::Problem;
+use num::cast::AsPrimitive;
+use num::{Bounded, FromPrimitive};
+use std::collections::VecDeque;
+
+/// Penalty subtracted from the fitness of chromosomes violating constraints,
+/// proportional to `Problem::violation`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Penalty {
+    /// A fixed penalty coefficient.
+    Static(f64),
+    /// A coefficient adapted every generation based on the best chromosome of the last `window`
+    /// generations: divided by `feasible_factor` when it was always feasible, multiplied by
+    /// `infeasible_factor` when it was always infeasible, and left unchanged otherwise.
+    Adaptive {
+        initial: f64,
+        feasible_factor: f64,
+        infeasible_factor: f64,
+        window: usize,
+    },
+}
+
+/// Fixes chromosomes that violate constraints, applied to offspring before they are evaluated.
+pub trait Repair<T: Problem> {
+    fn repair(&self, chromosome: Chromosome<T>) -> Chromosome<T>;
+}
+
+/// Current penalty coefficient of a run.
+pub(crate) struct PenaltyCoefficient {
+    penalty: Penalty,
+    value: f64,
+    history: VecDeque<bool>,
+}
+
+impl PenaltyCoefficient {
+    pub(crate) fn new(penalty: Penalty) -> Self {
+        let value = match penalty {
+            Penalty::Static(value) | Penalty::Adaptive { initial: value, .. } => value,
+        };
+
+        Self {
+            penalty,
+            value,
+            history: VecDeque::new(),
+        }
+    }
+
+    pub(crate) const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Record whether the best chromosome of a generation is feasible,
+    /// returning whether the coefficient changed.
+    pub(crate) fn update(&mut self, best_feasible: bool) -> bool {
+        let Penalty::Adaptive {
+            feasible_factor,
+            infeasible_factor,
+            window,
+            ..
+        } = self.penalty
+        else {
+            return false;
+        };
+
+        self.history.push_back(best_feasible);
+        if self.history.len() > window {
+            self.history.pop_front();
+        }
+        if self.history.len() < window {
+            return false;
+        }
+
+        if self.history.iter().all(|&feasible| feasible) {
+            self.value /= feasible_factor;
+        } else if self.history.iter().all(|&feasible| !feasible) {
+            self.value *= infeasible_factor;
+        } else {
+            return false;
+        }
+        self.history.clear();
+        true
+    }
+}
+
+/// `objective - coefficient * violation`, saturated to the range of the fitness type.
+pub(crate) fn penalize<F>(objective: F, violation: f64, coefficient: f64) -> F
+where
+    F: AsPrimitive<f64> + FromPrimitive + Bounded,
+{
+    if violation <= 0.0 || coefficient == 0.0 {
+        return objective;
+    }
+
+    let penalized = objective.as_() - coefficient * violation;
+    F::from_f64(penalized).unwrap_or_else(|| {
+        if penalized < 0.0 {
+            F::min_value()
+        } else {
+            F::max_value()
+        }
+    })
+}
@@ -1,14 +1,44 @@
+pub mod arithmetic;
+pub mod bitwise;
+pub mod blend_alpha;
+pub mod cut_and_splice;
+pub mod cycle;
+pub mod diagonal;
+pub mod edge_recombination;
+pub mod gene_pool;
+pub mod k_point;
+pub mod messy;
 pub mod order_one;
+pub mod partially_mapped;
+pub mod simulated_binary;
 pub mod single_point;
 pub mod uniform;
 
 use crate::chromosome::Chromosome;
+use crate::lineage::short_type_name;
 use crate::problem::Problem;
+use itertools::Itertools;
+use num::cast::AsPrimitive;
+use rand::seq::index::sample;
+use rand::thread_rng;
+use std::hash::Hash;
 
 pub enum Crossover {
-    OrderOne,
     SinglePoint,
+    TwoPoint,
+    KPoint(usize),
     Uniform(f64),
+    Diagonal(usize),
+    GenePool(usize),
+    CutAndSplice,
+    Messy(f64),
+}
+
+/// Crossovers creating new values for numeric alleles, see `GeneticBuilder::with_real_crossover_strategy`.
+pub enum RealCrossover {
+    Arithmetic,
+    BlendAlpha(f64),
+    SimulatedBinary(f64),
 }
 
 pub trait CrossoverStrategy<T: Problem> {
@@ -17,4 +47,106 @@ pub trait CrossoverStrategy<T: Problem> {
         father: Chromosome<T>,
         mother: Chromosome<T>,
     ) -> (Chromosome<T>, Chromosome<T>);
+
+    /// Number of parents recombined at once.
+    fn arity(&self) -> usize {
+        2
+    }
+
+    /// Name recorded in the `Lineage` of the children, the type name by default.
+    fn name(&self) -> &'static str {
+        short_type_name::<Self>()
+    }
+
+    /// Recombine a group of `arity` parents into any number of children.
+    /// The default pairs the parents up for `crossover`; an odd parent out is passed through.
+    fn recombine(&self, parents: Vec<Chromosome<T>>) -> Vec<Chromosome<T>> {
+        let mut parents = parents.into_iter();
+        let mut children = Vec::with_capacity(parents.len());
+
+        while let Some(father) = parents.next() {
+            match parents.next() {
+                Some(mother) => {
+                    let (son, daughter) = self.crossover(father, mother);
+                    children.push(son);
+                    children.push(daughter);
+                }
+                None => children.push(father),
+            }
+        }
+
+        children
+    }
+}
+
+/// Up to `k` distinct cut points in `1..length`, sorted.
+pub(crate) fn cut_points(length: usize, k: usize) -> Vec<usize> {
+    let candidates = length.saturating_sub(1);
+
+    sample(&mut thread_rng(), candidates, k.min(candidates))
+        .into_iter()
+        .map(|i| i + 1)
+        .sorted()
+        .collect()
+}
+
+/// Whether `child` holds exactly the same genes as `parent`, in any order.
+pub(crate) fn is_permutation_of<A: Eq + Hash>(child: &[A], parent: &[A]) -> bool {
+    child.len() == parent.len() && child.iter().counts() == parent.iter().counts()
+}
+
+/// Combine the numeric genes shared by both parents pairwise with `combine`,
+/// clamping the results to `Problem::gene_bounds`.
+/// Genes past the end of the shorter parent stay with the child of their parent.
+pub(crate) fn recombine_real<T, F>(
+    father: Chromosome<T>,
+    mother: Chromosome<T>,
+    mut combine: F,
+) -> (Chromosome<T>, Chromosome<T>)
+where
+    T: Problem,
+    T::Allele: AsPrimitive<f64>,
+    f64: AsPrimitive<T::Allele>,
+    F: FnMut(f64, f64) -> (f64, f64),
+{
+    let mut c1 = father.into_genes();
+    let mut c2 = mother.into_genes();
+
+    for (locus, (x, y)) in c1.iter_mut().zip(c2.iter_mut()).enumerate() {
+        let (a, b) = combine(x.as_(), y.as_());
+        let (a, b) = match T::gene_bounds(locus) {
+            Some((lower, upper)) => (a.clamp(lower, upper), b.clamp(lower, upper)),
+            None => (a, b),
+        };
+        *x = a.as_();
+        *y = b.as_();
+    }
+
+    (Chromosome::new(c1), Chromosome::new(c2))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::permutation::Permutation;
+
+    /// A problem over a single permutation, for testing the permutation crossovers.
+    #[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+    pub(crate) struct Tour;
+    impl Problem for Tour {
+        type Fitness = i32;
+        type Allele = Permutation;
+
+        fn fitness(&self, _chromosome: &Chromosome<Self>) -> Self::Fitness {
+            0
+        }
+
+        fn terminate(&self, _: &[Chromosome<Self>], _: u32, _: f64) -> bool {
+            true
+        }
+
+        fn genotype() -> Vec<Self::Allele> {
+            Vec::new()
+        }
+    }
 }
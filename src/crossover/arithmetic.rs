@@ -0,0 +1,34 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::{recombine_real, CrossoverStrategy};
+use crate::problem::Problem;
+use num::cast::AsPrimitive;
+use rand::random;
+use std::marker::PhantomData;
+
+/// Whole arithmetic crossover: both children are the same random convex combination
+/// of their parents, mirrored.
+#[derive(Clone, Default, Debug)]
+pub struct Arithmetic<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> CrossoverStrategy<T> for Arithmetic<T>
+where
+    T::Allele: AsPrimitive<f64>,
+    f64: AsPrimitive<T::Allele>,
+{
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        let weight = random::<f64>();
+
+        recombine_real(father, mother, |x, y| {
+            (
+                weight * x + (1.0 - weight) * y,
+                (1.0 - weight) * x + weight * y,
+            )
+        })
+    }
+}
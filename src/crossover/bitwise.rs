@@ -0,0 +1,113 @@
+use crate::bitstring::BitString;
+use crate::chromosome::Chromosome;
+use crate::crossover::{cut_points, CrossoverStrategy};
+use crate::problem::Problem;
+use itertools::Itertools;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+/// K-point crossover on packed bit strings, exchanging whole words between the cut points.
+#[derive(Clone, Debug)]
+pub struct BitKPoint<T: Problem<Allele = BitString>> {
+    _problem: PhantomData<T>,
+    k: usize,
+}
+
+impl<T: Problem<Allele = BitString>> BitKPoint<T> {
+    #[must_use]
+    pub fn new(k: usize) -> Self {
+        Self {
+            _problem: PhantomData,
+            k,
+        }
+    }
+}
+
+impl<T: Problem<Allele = BitString>> CrossoverStrategy<T> for BitKPoint<T> {
+    fn crossover(
+        &self,
+        mut father: Chromosome<T>,
+        mut mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        for (f, m) in father
+            .genes_mut()
+            .iter_mut()
+            .zip(mother.genes_mut().iter_mut())
+        {
+            let length = f.len().min(m.len());
+            let mask = cut_points(length, self.k)
+                .into_iter()
+                .chain(std::iter::once(length))
+                .tuples()
+                .map(|(start, end)| BitString::range_mask(length, start, end))
+                .fold(vec![0; length.div_ceil(64)], |acc, segment| {
+                    acc.iter().zip(segment).map(|(a, s)| a | s).collect()
+                });
+
+            f.swap_masked(m, &mask);
+        }
+
+        (
+            Chromosome::new(father.into_genes()),
+            Chromosome::new(mother.into_genes()),
+        )
+    }
+}
+
+/// Uniform crossover on packed bit strings: every bit stays with its parent with
+/// probability `crossover_rate` and is exchanged otherwise.
+#[derive(Clone, Debug)]
+pub struct BitUniform<T: Problem<Allele = BitString>> {
+    _problem: PhantomData<T>,
+    crossover_rate: f64,
+}
+
+impl<T: Problem<Allele = BitString>> BitUniform<T> {
+    #[must_use]
+    pub fn new(crossover_rate: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            crossover_rate,
+        }
+    }
+
+    fn mask(&self, length: usize) -> Vec<u64> {
+        let mut rng = thread_rng();
+        if (self.crossover_rate - 0.5).abs() < f64::EPSILON {
+            let mut mask: Vec<u64> = (0..length.div_ceil(64)).map(|_| rng.gen()).collect();
+            // Bits past `length` belong to the longer parent only and must stay in place
+            if let (Some(last), tail @ 1..) = (mask.last_mut(), length % 64) {
+                *last &= (1 << tail) - 1;
+            }
+            return mask;
+        }
+
+        (0..length)
+            .map(|_| !rng.gen_bool(self.crossover_rate))
+            .collect::<BitString>()
+            .words()
+            .to_vec()
+    }
+}
+
+impl<T: Problem<Allele = BitString>> CrossoverStrategy<T> for BitUniform<T> {
+    fn crossover(
+        &self,
+        mut father: Chromosome<T>,
+        mut mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        for (f, m) in father
+            .genes_mut()
+            .iter_mut()
+            .zip(mother.genes_mut().iter_mut())
+        {
+            let mask = self.mask(f.len().min(m.len()));
+            f.swap_masked(m, &mask);
+        }
+
+        (
+            Chromosome::new(father.into_genes()),
+            Chromosome::new(mother.into_genes()),
+        )
+    }
+}
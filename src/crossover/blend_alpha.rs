@@ -0,0 +1,49 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::{recombine_real, CrossoverStrategy};
+use crate::problem::Problem;
+use num::cast::AsPrimitive;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+/// Blend crossover (BLX-alpha): every child gene is drawn uniformly from the interval spanned
+/// by the parent genes, extended on both sides by `alpha` times its length.
+#[derive(Clone, Debug)]
+pub struct BlendAlpha<T: Problem> {
+    _problem: PhantomData<T>,
+    alpha: f64,
+}
+
+impl<T: Problem> BlendAlpha<T> {
+    #[must_use]
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            alpha,
+        }
+    }
+}
+
+impl<T: Problem> CrossoverStrategy<T> for BlendAlpha<T>
+where
+    T::Allele: AsPrimitive<f64>,
+    f64: AsPrimitive<T::Allele>,
+{
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        recombine_real(father, mother, |x, y| {
+            let extent = self.alpha * (x - y).abs();
+            let (lower, upper) = (x.min(y) - extent, x.max(y) + extent);
+            if lower >= upper {
+                return (x, y);
+            }
+
+            (
+                thread_rng().gen_range(lower..=upper),
+                thread_rng().gen_range(lower..=upper),
+            )
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::CrossoverStrategy;
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+/// Cut-and-splice crossover for variable-length chromosomes.
+///
+/// Each parent is cut at its own random point and the tails are exchanged,
+/// so the children can be shorter or longer than their parents.
+#[derive(Clone, Default, Debug)]
+pub struct CutAndSplice<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> CrossoverStrategy<T> for CutAndSplice<T> {
+    fn crossover(
+        &self,
+        mut father: Chromosome<T>,
+        mut mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        let father_point = thread_rng().gen_range(0..=father.genes().len());
+        let mother_point = thread_rng().gen_range(0..=mother.genes().len());
+
+        let mut father_split = father.genes_mut().split_off(father_point);
+        let mut mother_split = mother.genes_mut().split_off(mother_point);
+
+        father.genes_mut().append(&mut mother_split);
+        mother.genes_mut().append(&mut father_split);
+
+        (
+            Chromosome::new(father.into_genes()),
+            Chromosome::new(mother.into_genes()),
+        )
+    }
+}
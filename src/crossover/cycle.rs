@@ -0,0 +1,119 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::{is_permutation_of, CrossoverStrategy};
+use crate::permutation::{cross_pairwise, Permutation};
+use crate::problem::Problem;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Cycle crossover (CX) for permutations.
+///
+/// The loci are partitioned into the cycles formed by the two parents; children take every
+/// other cycle from the same parent, so each gene keeps the absolute position it had in one of them.
+///
+/// # Panics
+/// Will panic if the permutations at the same locus of both parents do not hold the same elements.
+#[derive(Clone, Default, Debug)]
+pub struct Cycle<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> Cycle<T> {
+    fn cross<A: Eq + Hash + Clone>(father: &[A], mother: &[A]) -> (Vec<A>, Vec<A>) {
+        assert!(
+            is_permutation_of(mother, father),
+            "parents must be permutations of the same elements"
+        );
+        let in_father: HashMap<_, _> = father.iter().enumerate().map(|(i, g)| (g, i)).collect();
+
+        let mut c1 = father.to_vec();
+        let mut c2 = mother.to_vec();
+        let mut visited = vec![false; father.len()];
+        let mut swap = false;
+
+        for start in 0..father.len() {
+            if visited[start] {
+                continue;
+            }
+
+            let mut locus = start;
+            while !visited[locus] {
+                visited[locus] = true;
+                if swap {
+                    std::mem::swap(&mut c1[locus], &mut c2[locus]);
+                }
+                locus = in_father[&mother[locus]];
+            }
+            swap = !swap;
+        }
+
+        debug_assert!(is_permutation_of(&c1, father));
+        debug_assert!(is_permutation_of(&c2, mother));
+
+        (c1, c2)
+    }
+}
+
+impl<T, A> CrossoverStrategy<T> for Cycle<T>
+where
+    T: Problem<Allele = Permutation<A>>,
+    A: Eq + Hash + Clone,
+{
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        cross_pairwise(father, mother, Self::cross)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crossover::tests::Tour;
+    use rand::seq::SliceRandom;
+    use rand::thread_rng;
+
+    #[test]
+    fn children_are_permutations_keeping_parental_positions() {
+        let mut rng = thread_rng();
+        for length in [0, 1, 2, 3, 5, 8, 13, 50] {
+            for _ in 0..300 {
+                let mut father = (0..length).collect::<Vec<_>>();
+                let mut mother = father.clone();
+                father.shuffle(&mut rng);
+                mother.shuffle(&mut rng);
+
+                let (son, daughter) = Cycle::<Tour>::default().crossover(
+                    Chromosome::new(vec![Permutation::try_from(father.clone()).unwrap()]),
+                    Chromosome::new(vec![Permutation::try_from(mother.clone()).unwrap()]),
+                );
+                let (son, daughter) = (son.genes()[0].as_slice(), daughter.genes()[0].as_slice());
+
+                assert!(is_permutation_of(son, &father), "{father:?} x {mother:?}");
+                assert!(
+                    is_permutation_of(daughter, &mother),
+                    "{father:?} x {mother:?}"
+                );
+                for child in [son, daughter] {
+                    for (i, gene) in child.iter().enumerate() {
+                        assert!(
+                            father[i] == *gene || mother[i] == *gene,
+                            "{gene} moved from locus {i} in {father:?} x {mother:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "parents must be permutations of the same elements")]
+    fn parents_of_different_elements_are_rejected() {
+        Cycle::<Tour>::default().crossover(
+            Chromosome::new(vec![Permutation::identity(3)]),
+            Chromosome::new(vec![Permutation::identity(4)]),
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::{cut_points, CrossoverStrategy};
+use crate::problem::Problem;
+use itertools::Itertools;
+use std::marker::PhantomData;
+
+/// Diagonal crossover: `n` parents are cut at the same `n - 1` points and
+/// child `i` takes segment `j` from parent `i + j` (modulo `n`), producing `n` children.
+#[derive(Clone, Debug)]
+pub struct Diagonal<T: Problem> {
+    _problem: PhantomData<T>,
+    parents: usize,
+}
+
+impl<T: Problem> Diagonal<T> {
+    #[must_use]
+    pub fn new(parents: usize) -> Self {
+        Self {
+            _problem: PhantomData,
+            parents: parents.max(2),
+        }
+    }
+}
+
+impl<T: Problem> CrossoverStrategy<T> for Diagonal<T> {
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        self.recombine(vec![father, mother])
+            .into_iter()
+            .collect_tuple()
+            .unwrap()
+    }
+
+    fn arity(&self) -> usize {
+        self.parents
+    }
+
+    fn recombine(&self, parents: Vec<Chromosome<T>>) -> Vec<Chromosome<T>> {
+        let n = parents.len();
+        let length = parents.iter().map(|p| p.genes().len()).min().unwrap_or(0);
+        let bounds = std::iter::once(0)
+            .chain(cut_points(length, n - 1))
+            .chain(std::iter::once(length))
+            .collect_vec();
+
+        (0..n)
+            .map(|i| {
+                let mut genes = bounds
+                    .iter()
+                    .tuple_windows()
+                    .enumerate()
+                    .flat_map(|(j, (&start, &end))| {
+                        parents[(i + j) % n].genes()[start..end].to_vec()
+                    })
+                    .collect_vec();
+                genes.extend_from_slice(&parents[i].genes()[length..]);
+
+                Chromosome::new(genes)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,105 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::{is_permutation_of, CrossoverStrategy};
+use crate::permutation::{cross_pairwise, Permutation};
+use crate::problem::Problem;
+use itertools::Itertools;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+use std::collections::{BTreeSet, HashMap};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Edge recombination crossover (ERX) for tours.
+///
+/// Children are built from an adjacency table holding the neighbours of every gene in both
+/// cyclic parents, always moving to the neighbour with the fewest remaining neighbours.
+/// This preserves edges rather than absolute positions.
+#[derive(Clone, Default, Debug)]
+pub struct EdgeRecombination<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> EdgeRecombination<T> {
+    /// Neighbours of every locus of `father`, with both parents mapped onto `father`'s loci.
+    fn adjacency<A: Eq + Hash>(father: &[A], mother: &[A]) -> Vec<BTreeSet<usize>> {
+        let index: HashMap<_, _> = father.iter().enumerate().map(|(i, g)| (g, i)).collect();
+        let mut table = vec![BTreeSet::new(); father.len()];
+
+        let mother = mother.iter().map(|g| index[g]).collect_vec();
+        for tour in [(0..father.len()).collect_vec(), mother] {
+            for (a, b) in tour.into_iter().circular_tuple_windows() {
+                if a != b {
+                    table[a].insert(b);
+                    table[b].insert(a);
+                }
+            }
+        }
+
+        table
+    }
+
+    fn child(mut table: Vec<BTreeSet<usize>>, start: usize) -> Vec<usize> {
+        let mut remaining: BTreeSet<_> = (0..table.len()).collect();
+        let mut tour = Vec::with_capacity(table.len());
+        let mut current = start;
+
+        loop {
+            tour.push(current);
+            remaining.remove(&current);
+            for neighbours in &mut table {
+                neighbours.remove(&current);
+            }
+
+            let candidates = if table[current].is_empty() {
+                remaining.iter().copied().collect_vec()
+            } else {
+                let fewest = table[current]
+                    .iter()
+                    .map(|&n| table[n].len())
+                    .min()
+                    .unwrap();
+                table[current]
+                    .iter()
+                    .copied()
+                    .filter(|&n| table[n].len() == fewest)
+                    .collect_vec()
+            };
+
+            match candidates.choose(&mut thread_rng()) {
+                Some(&next) => current = next,
+                None => return tour,
+            }
+        }
+    }
+
+    fn cross<A: Eq + Hash + Clone>(father: &[A], mother: &[A]) -> (Vec<A>, Vec<A>) {
+        if father.is_empty() {
+            return (father.to_vec(), mother.to_vec());
+        }
+
+        let table = Self::adjacency(father, mother);
+        let mother_start = father.iter().position(|g| *g == mother[0]).unwrap();
+
+        let to_genes = |tour: Vec<usize>| tour.into_iter().map(|i| father[i].clone()).collect_vec();
+        let c1 = to_genes(Self::child(table.clone(), 0));
+        let c2 = to_genes(Self::child(table, mother_start));
+        debug_assert!(is_permutation_of(&c1, father));
+        debug_assert!(is_permutation_of(&c2, mother));
+
+        (c1, c2)
+    }
+}
+
+impl<T, A> CrossoverStrategy<T> for EdgeRecombination<T>
+where
+    T: Problem<Allele = Permutation<A>>,
+    A: Eq + Hash + Clone,
+{
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        cross_pairwise(father, mother, Self::cross)
+    }
+}
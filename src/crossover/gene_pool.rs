@@ -0,0 +1,58 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::CrossoverStrategy;
+use crate::problem::Problem;
+use itertools::Itertools;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+use std::marker::PhantomData;
+
+/// Gene-pool recombination: every gene of every child is drawn from the pool of genes
+/// that all `n` parents hold at the same locus, producing `n` children.
+#[derive(Clone, Debug)]
+pub struct GenePool<T: Problem> {
+    _problem: PhantomData<T>,
+    parents: usize,
+}
+
+impl<T: Problem> GenePool<T> {
+    #[must_use]
+    pub fn new(parents: usize) -> Self {
+        Self {
+            _problem: PhantomData,
+            parents: parents.max(2),
+        }
+    }
+}
+
+impl<T: Problem> CrossoverStrategy<T> for GenePool<T> {
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        self.recombine(vec![father, mother])
+            .into_iter()
+            .collect_tuple()
+            .unwrap()
+    }
+
+    fn arity(&self) -> usize {
+        self.parents
+    }
+
+    fn recombine(&self, parents: Vec<Chromosome<T>>) -> Vec<Chromosome<T>> {
+        let length = parents.iter().map(|p| p.genes().len()).min().unwrap_or(0);
+
+        parents
+            .iter()
+            .map(|own| {
+                let mut genes = (0..length)
+                    .map(|locus| parents.choose(&mut thread_rng()).unwrap().genes()[locus].clone())
+                    .collect_vec();
+                genes.extend_from_slice(&own.genes()[length..]);
+
+                Chromosome::new(genes)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,48 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::{cut_points, CrossoverStrategy};
+use crate::problem::Problem;
+use itertools::Itertools;
+use std::marker::PhantomData;
+
+/// Cuts both parents at `k` distinct sorted points and exchanges every other segment.
+///
+/// Only the loci shared by both parents can be cut; chromosomes with fewer than `k + 1`
+/// shared loci are cut at every possible point, and those with a single locus are left unchanged.
+#[derive(Clone, Debug)]
+pub struct KPoint<T: Problem> {
+    _problem: PhantomData<T>,
+    k: usize,
+}
+
+impl<T: Problem> KPoint<T> {
+    #[must_use]
+    pub fn new(k: usize) -> Self {
+        Self {
+            _problem: PhantomData,
+            k,
+        }
+    }
+}
+
+impl<T: Problem> CrossoverStrategy<T> for KPoint<T> {
+    fn crossover(
+        &self,
+        mut father: Chromosome<T>,
+        mut mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        let length = father.genes().len().min(mother.genes().len());
+        let cx_points = cut_points(length, self.k)
+            .into_iter()
+            .chain(std::iter::once(length))
+            .collect_vec();
+
+        for (start, end) in cx_points.into_iter().tuples() {
+            father.genes_mut()[start..end].swap_with_slice(&mut mother.genes_mut()[start..end]);
+        }
+
+        (
+            Chromosome::new(father.into_genes()),
+            Chromosome::new(mother.into_genes()),
+        )
+    }
+}
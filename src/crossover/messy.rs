@@ -0,0 +1,54 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::CrossoverStrategy;
+use crate::problem::Problem;
+use itertools::Itertools;
+use rand::prelude::SliceRandom;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+/// Messy crossover for variable-length chromosomes.
+///
+/// Each parent is cut at an independent random point with probability `cut_rate`;
+/// the resulting segments are shuffled and spliced back into two children.
+#[derive(Clone, Debug)]
+pub struct Messy<T: Problem> {
+    _problem: PhantomData<T>,
+    cut_rate: f64,
+}
+
+impl<T: Problem> Messy<T> {
+    #[must_use]
+    pub fn new(cut_rate: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            cut_rate,
+        }
+    }
+
+    fn cut(&self, mut genes: Vec<T::Allele>) -> Vec<Vec<T::Allele>> {
+        if genes.len() > 1 && thread_rng().gen_bool(self.cut_rate) {
+            let tail = genes.split_off(thread_rng().gen_range(1..genes.len()));
+            vec![genes, tail]
+        } else {
+            vec![genes]
+        }
+    }
+}
+
+impl<T: Problem> CrossoverStrategy<T> for Messy<T> {
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        let mut segments = self.cut(father.into_genes());
+        segments.append(&mut self.cut(mother.into_genes()));
+        segments.shuffle(&mut thread_rng());
+
+        let splice = thread_rng().gen_range(1..segments.len());
+        let mother = segments.split_off(splice).into_iter().concat();
+        let father = segments.into_iter().concat();
+
+        (Chromosome::new(father), Chromosome::new(mother))
+    }
+}
@@ -1,48 +1,52 @@
 use crate::chromosome::Chromosome;
-use crate::crossover::{Crossover, CrossoverStrategy};
+use crate::crossover::CrossoverStrategy;
+use crate::permutation::{cross_pairwise, Permutation};
 use crate::problem::Problem;
 use itertools::Itertools;
 use rand::{thread_rng, Rng};
 use std::collections::HashSet;
+use std::hash::Hash;
 use std::marker::PhantomData;
 
+/// Order crossover (OX1) for permutations.
+///
+/// Each child inherits a random segment from one parent and the remaining elements
+/// in the order they appear in the other parent.
 #[derive(Clone, Default, Debug)]
 pub struct OrderOne<T: Problem> {
     _problem: PhantomData<T>,
 }
 
-impl<T: Problem> CrossoverStrategy<T> for OrderOne<T> {
-    fn crossover(
-        &self,
-        father: Chromosome<T>,
-        mother: Chromosome<T>,
-    ) -> (Chromosome<T>, Chromosome<T>) {
+impl<T: Problem> OrderOne<T> {
+    fn cross<A: Eq + Hash + Clone>(father: &[A], mother: &[A]) -> (Vec<A>, Vec<A>) {
+        if father.is_empty() {
+            return (father.to_vec(), mother.to_vec());
+        }
+
         let (mut start, mut end) = (
-            thread_rng().gen_range(0..father.genes.len()),
-            thread_rng().gen_range(0..father.genes.len()),
+            thread_rng().gen_range(0..father.len()),
+            thread_rng().gen_range(0..father.len()),
         );
         if start > end {
             std::mem::swap(&mut start, &mut end);
         }
-        let slice1 = &father.genes[start..end];
+        let slice1 = &father[start..end];
         let slice1_set: HashSet<_> = slice1.iter().cloned().collect();
         let p2_contrib = mother
-            .genes
             .iter()
             .filter(|&x| !slice1_set.contains(x))
             .collect_vec();
         let (head1, tail1) = p2_contrib.split_at(start);
 
-        let slice2 = &mother.genes[start..end];
+        let slice2 = &mother[start..end];
         let slice2_set: HashSet<_> = slice2.iter().cloned().collect();
         let p1_contrib = father
-            .genes
             .iter()
             .filter(|&x| !slice2_set.contains(x))
             .collect_vec();
         let (head2, tail2) = p1_contrib.split_at(start);
 
-        let (c1, c2) = (
+        (
             head1
                 .iter()
                 .copied()
@@ -57,8 +61,20 @@ impl<T: Problem> CrossoverStrategy<T> for OrderOne<T> {
                 .chain(tail2.iter().copied())
                 .cloned()
                 .collect_vec(),
-        );
+        )
+    }
+}
 
-        (Chromosome::new(c1), Chromosome::new(c2))
+impl<T, A> CrossoverStrategy<T> for OrderOne<T>
+where
+    T: Problem<Allele = Permutation<A>>,
+    A: Eq + Hash + Clone,
+{
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        cross_pairwise(father, mother, Self::cross)
     }
 }
@@ -0,0 +1,122 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::{is_permutation_of, CrossoverStrategy};
+use crate::permutation::{cross_pairwise, Permutation};
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Partially mapped crossover (PMX) for permutations.
+///
+/// Each child inherits a random segment from one parent; the remaining loci are taken from the
+/// other parent, following the mapping defined by the segment whenever a value would repeat.
+///
+/// # Panics
+/// Will panic if the permutations at the same locus of both parents do not hold the same elements.
+#[derive(Clone, Default, Debug)]
+pub struct PartiallyMapped<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> PartiallyMapped<T> {
+    fn child<A: Eq + Hash + Clone>(donor: &[A], other: &[A], start: usize, end: usize) -> Vec<A> {
+        let segment: HashMap<_, _> = donor[start..end]
+            .iter()
+            .enumerate()
+            .map(|(i, g)| (g, start + i))
+            .collect();
+
+        other
+            .iter()
+            .enumerate()
+            .map(|(i, gene)| {
+                if (start..end).contains(&i) {
+                    return donor[i].clone();
+                }
+
+                let mut gene = gene;
+                while let Some(&locus) = segment.get(gene) {
+                    gene = &other[locus];
+                }
+                gene.clone()
+            })
+            .collect()
+    }
+
+    fn cross<A: Eq + Hash + Clone>(father: &[A], mother: &[A]) -> (Vec<A>, Vec<A>) {
+        assert!(
+            is_permutation_of(mother, father),
+            "parents must be permutations of the same elements"
+        );
+        let (mut start, mut end) = (
+            thread_rng().gen_range(0..=father.len()),
+            thread_rng().gen_range(0..=father.len()),
+        );
+        if start > end {
+            std::mem::swap(&mut start, &mut end);
+        }
+
+        let c1 = Self::child(father, mother, start, end);
+        let c2 = Self::child(mother, father, start, end);
+        debug_assert!(is_permutation_of(&c1, father));
+        debug_assert!(is_permutation_of(&c2, mother));
+
+        (c1, c2)
+    }
+}
+
+impl<T, A> CrossoverStrategy<T> for PartiallyMapped<T>
+where
+    T: Problem<Allele = Permutation<A>>,
+    A: Eq + Hash + Clone,
+{
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        cross_pairwise(father, mother, Self::cross)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crossover::tests::Tour;
+    use rand::seq::SliceRandom;
+
+    #[test]
+    fn children_are_permutations_of_their_parents() {
+        let mut rng = thread_rng();
+        for length in [0, 1, 2, 3, 5, 8, 13, 50] {
+            for _ in 0..300 {
+                let mut father = (0..length).collect::<Vec<_>>();
+                let mut mother = father.clone();
+                father.shuffle(&mut rng);
+                mother.shuffle(&mut rng);
+
+                let (son, daughter) = PartiallyMapped::<Tour>::default().crossover(
+                    Chromosome::new(vec![Permutation::try_from(father.clone()).unwrap()]),
+                    Chromosome::new(vec![Permutation::try_from(mother.clone()).unwrap()]),
+                );
+                let (son, daughter) = (son.genes()[0].as_slice(), daughter.genes()[0].as_slice());
+
+                assert!(is_permutation_of(son, &father), "{father:?} x {mother:?}");
+                assert!(
+                    is_permutation_of(daughter, &mother),
+                    "{father:?} x {mother:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "parents must be permutations of the same elements")]
+    fn parents_of_different_elements_are_rejected() {
+        PartiallyMapped::<Tour>::default().crossover(
+            Chromosome::new(vec![Permutation::identity(3)]),
+            Chromosome::new(vec![Permutation::identity(4)]),
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::{recombine_real, CrossoverStrategy};
+use crate::problem::Problem;
+use num::cast::AsPrimitive;
+use rand::random;
+use std::marker::PhantomData;
+
+/// Simulated binary crossover (SBX).
+///
+/// Children are spread symmetrically around their parents' mean with the same spread
+/// distribution as single-point crossover on binary strings. A larger distribution index `eta`
+/// keeps children closer to their parents.
+#[derive(Clone, Debug)]
+pub struct SimulatedBinary<T: Problem> {
+    _problem: PhantomData<T>,
+    eta: f64,
+}
+
+impl<T: Problem> SimulatedBinary<T> {
+    #[must_use]
+    pub fn new(eta: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            eta,
+        }
+    }
+
+    fn spread(&self) -> f64 {
+        let u = random::<f64>();
+        let exponent = 1.0 / (self.eta + 1.0);
+
+        if u <= 0.5 {
+            (2.0 * u).powf(exponent)
+        } else {
+            (1.0 / (2.0 * (1.0 - u))).powf(exponent)
+        }
+    }
+}
+
+impl<T: Problem> CrossoverStrategy<T> for SimulatedBinary<T>
+where
+    T::Allele: AsPrimitive<f64>,
+    f64: AsPrimitive<T::Allele>,
+{
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        recombine_real(father, mother, |x, y| {
+            let beta = self.spread();
+
+            (
+                0.5 * ((1.0 + beta) * x + (1.0 - beta) * y),
+                0.5 * ((1.0 - beta) * x + (1.0 + beta) * y),
+            )
+        })
+    }
+}
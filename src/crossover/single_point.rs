@@ -1,9 +1,7 @@
 use crate::chromosome::Chromosome;
-use crate::crossover::{Crossover, CrossoverStrategy};
+use crate::crossover::CrossoverStrategy;
 use crate::problem::Problem;
-use itertools::Itertools;
 use rand::{thread_rng, Rng};
-use std::collections::HashSet;
 use std::marker::PhantomData;
 
 #[derive(Clone, Default, Debug)]
@@ -17,14 +15,25 @@ impl<T: Problem> CrossoverStrategy<T> for SinglePoint<T> {
         mut father: Chromosome<T>,
         mut mother: Chromosome<T>,
     ) -> (Chromosome<T>, Chromosome<T>) {
-        let cx_point = thread_rng().gen_range(0..father.get_size());
+        let length = father.genes().len().min(mother.genes().len());
+        if length <= 1 {
+            return (
+                Chromosome::new(father.into_genes()),
+                Chromosome::new(mother.into_genes()),
+            );
+        }
 
-        let mut father_split = father.genes.split_off(cx_point);
-        let mut mother_split = mother.genes.split_off(cx_point);
+        let cx_point = thread_rng().gen_range(1..length);
 
-        father.genes.append(&mut mother_split);
-        mother.genes.append(&mut father_split);
+        let mut father_split = father.genes_mut().split_off(cx_point);
+        let mut mother_split = mother.genes_mut().split_off(cx_point);
 
-        (Chromosome::new(father.genes), Chromosome::new(mother.genes))
+        father.genes_mut().append(&mut mother_split);
+        mother.genes_mut().append(&mut father_split);
+
+        (
+            Chromosome::new(father.into_genes()),
+            Chromosome::new(mother.into_genes()),
+        )
     }
 }
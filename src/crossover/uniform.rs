@@ -1,9 +1,7 @@
 use crate::chromosome::Chromosome;
-use crate::crossover::{Crossover, CrossoverStrategy};
+use crate::crossover::CrossoverStrategy;
 use crate::problem::Problem;
-use itertools::Itertools;
 use rand::{thread_rng, Rng};
-use std::collections::HashSet;
 use std::marker::PhantomData;
 
 #[derive(Clone, Default, Debug)]
@@ -15,7 +13,7 @@ pub struct Uniform<T: Problem> {
 impl<T: Problem> Uniform<T> {
     pub fn new(crossover_rate: f64) -> Self {
         Self {
-            _problem: PhantomData::default(),
+            _problem: PhantomData,
             crossover_rate,
         }
     }
@@ -24,13 +22,17 @@ impl<T: Problem> Uniform<T> {
 impl<T: Problem> CrossoverStrategy<T> for Uniform<T> {
     fn crossover(
         &self,
-        father: Chromosome<T>,
-        mother: Chromosome<T>,
+        mut father: Chromosome<T>,
+        mut mother: Chromosome<T>,
     ) -> (Chromosome<T>, Chromosome<T>) {
-        let (child1, child2) = father
-            .genes
+        let length = father.genes().len().min(mother.genes().len());
+        let mut father_tail = father.genes_mut().split_off(length);
+        let mut mother_tail = mother.genes_mut().split_off(length);
+
+        let (mut child1, mut child2): (Vec<_>, Vec<_>) = father
+            .into_genes()
             .into_iter()
-            .zip(mother.genes.into_iter())
+            .zip(mother.into_genes())
             .map(|(f, m)| {
                 if thread_rng().gen_bool(self.crossover_rate) {
                     (f, m)
@@ -39,6 +41,8 @@ impl<T: Problem> CrossoverStrategy<T> for Uniform<T> {
                 }
             })
             .unzip();
+        child1.append(&mut father_tail);
+        child2.append(&mut mother_tail);
 
         (Chromosome::new(child1), Chromosome::new(child2))
     }
@@ -0,0 +1,182 @@
+use crate::chromosome::Chromosome;
+use crate::permutation::Permutation;
+use crate::problem::Problem;
+use itertools::{EitherOrBoth, Itertools};
+use num::cast::AsPrimitive;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Distance between two chromosomes in genotype space, used for diversity preservation.
+pub trait Distance<T: Problem> {
+    fn distance(&self, a: &Chromosome<T>, b: &Chromosome<T>) -> f64;
+}
+
+/// Number of loci at which the genes differ.
+/// Chromosomes of unequal length additionally differ at every locus past the shorter one.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct Hamming;
+
+impl<T: Problem> Distance<T> for Hamming {
+    fn distance(&self, a: &Chromosome<T>, b: &Chromosome<T>) -> f64 {
+        let differing = a
+            .genes()
+            .iter()
+            .zip(b.genes().iter())
+            .filter(|(x, y)| x != y)
+            .count();
+
+        (differing + a.genes().len().abs_diff(b.genes().len())) as f64
+    }
+}
+
+/// Distance chosen by the problem through `Problem::distance`.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct ProblemMetric;
+
+impl<T: Problem> Distance<T> for ProblemMetric {
+    fn distance(&self, a: &Chromosome<T>, b: &Chromosome<T>) -> f64 {
+        T::distance(a, b)
+    }
+}
+
+/// Number of pairs of elements ordered differently in two permutations,
+/// summed over the permutations at the same locus.
+/// Permutations at the same locus must hold the same elements.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct KendallTau;
+
+impl<T: Problem<Allele = Permutation<A>>, A: Eq + Hash> Distance<T> for KendallTau {
+    fn distance(&self, a: &Chromosome<T>, b: &Chromosome<T>) -> f64 {
+        sum_pairwise(a, b, |a, b| {
+            relative_positions(a, b)
+                .iter()
+                .tuple_combinations()
+                .filter(|(x, y)| x > y)
+                .count()
+        })
+    }
+}
+
+/// Minimum number of transpositions turning one permutation into the other,
+/// summed over the permutations at the same locus.
+/// Permutations at the same locus must hold the same elements.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct Cayley;
+
+impl<T: Problem<Allele = Permutation<A>>, A: Eq + Hash> Distance<T> for Cayley {
+    fn distance(&self, a: &Chromosome<T>, b: &Chromosome<T>) -> f64 {
+        sum_pairwise(a, b, |a, b| {
+            let positions = relative_positions(a, b);
+            let mut visited = vec![false; positions.len()];
+            let mut cycles = 0;
+
+            for start in 0..positions.len() {
+                if visited[start] {
+                    continue;
+                }
+                cycles += 1;
+
+                let mut i = start;
+                while !visited[i] {
+                    visited[i] = true;
+                    i = positions[i];
+                }
+            }
+
+            positions.len() - cycles
+        })
+    }
+}
+
+/// Number of edges of the cyclic tour `a` that do not appear in the cyclic tour `b`,
+/// regardless of direction, summed over the permutations at the same locus.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct Adjacency;
+
+impl<T: Problem<Allele = Permutation<A>>, A: Eq + Hash> Distance<T> for Adjacency {
+    fn distance(&self, a: &Chromosome<T>, b: &Chromosome<T>) -> f64 {
+        sum_pairwise(a, b, |a, b| {
+            let index: HashMap<_, _> = a.iter().enumerate().map(|(i, g)| (g, i)).collect();
+            let edges = |tour: &[A]| -> HashSet<(usize, usize)> {
+                tour.iter()
+                    .circular_tuple_windows()
+                    .filter_map(|(x, y)| Some((*index.get(x)?, *index.get(y)?)))
+                    .map(|(x, y)| (x.min(y), x.max(y)))
+                    .collect()
+            };
+
+            edges(a).difference(&edges(b)).count()
+        })
+    }
+}
+
+/// Euclidean distance between numeric genes.
+/// Genes past the end of the shorter chromosome are compared against 0.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct Euclidean;
+
+impl<T: Problem> Distance<T> for Euclidean
+where
+    T::Allele: AsPrimitive<f64>,
+{
+    fn distance(&self, a: &Chromosome<T>, b: &Chromosome<T>) -> f64 {
+        differences(a, b).map(|d| d.powi(2)).sum::<f64>().sqrt()
+    }
+}
+
+/// Manhattan (city block) distance between numeric genes.
+/// Genes past the end of the shorter chromosome are compared against 0.
+#[derive(Default, Copy, Clone, Debug)]
+pub struct Manhattan;
+
+impl<T: Problem> Distance<T> for Manhattan
+where
+    T::Allele: AsPrimitive<f64>,
+{
+    fn distance(&self, a: &Chromosome<T>, b: &Chromosome<T>) -> f64 {
+        differences(a, b).map(f64::abs).sum()
+    }
+}
+
+/// Difference between the numeric genes at every locus of either chromosome,
+/// a missing gene counting as 0.
+fn differences<'a, T: Problem>(
+    a: &'a Chromosome<T>,
+    b: &'a Chromosome<T>,
+) -> impl Iterator<Item = f64> + 'a
+where
+    T::Allele: AsPrimitive<f64>,
+{
+    a.genes()
+        .iter()
+        .zip_longest(b.genes())
+        .map(|genes| match genes {
+            EitherOrBoth::Both(&x, &y) => x.as_() - y.as_(),
+            EitherOrBoth::Left(&x) => x.as_(),
+            EitherOrBoth::Right(&y) => -y.as_(),
+        })
+}
+
+/// Sum of `distance` over the permutations at the same locus of both chromosomes.
+fn sum_pairwise<T, A>(
+    a: &Chromosome<T>,
+    b: &Chromosome<T>,
+    distance: impl Fn(&[A], &[A]) -> usize,
+) -> f64
+where
+    T: Problem<Allele = Permutation<A>>,
+{
+    a.genes()
+        .iter()
+        .zip(b.genes())
+        .map(|(a, b)| distance(a, b))
+        .sum::<usize>() as f64
+}
+
+/// For every position of `a`, the position at which `b` holds the same element.
+fn relative_positions<A: Eq + Hash>(a: &[A], b: &[A]) -> Vec<usize> {
+    let in_b: HashMap<_, _> = b.iter().enumerate().map(|(i, g)| (g, i)).collect();
+    debug_assert_eq!(in_b.len(), a.len(), "permutations of different elements");
+
+    a.iter().map(|g| in_b[g]).collect()
+}
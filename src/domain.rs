@@ -0,0 +1,228 @@
+use crate::chromosome::Chromosome;
+use crate::constraint::Repair;
+use crate::problem::Problem;
+use rand::prelude::SliceRandom;
+use rand::{thread_rng, Rng};
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::ops::RangeInclusive;
+
+/// Values a single gene may take, declared through `Problem::gene_domain`.
+///
+/// Categorical domains hold any `Clone + PartialEq` allele, like strings or plain enums.
+/// Integer and boolean domains are built with `Domain::integer` and `Domain::boolean`,
+/// which need the allele to convert to and from integers, see `DomainValue`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Domain<A> {
+    /// Any integer in the range.
+    Integer(RangeInclusive<i64>, Conversion<A>),
+    /// One of the listed values, in a meaningful order for creep mutation.
+    Categorical(Vec<A>),
+    /// Either `0`/`false` or `1`/`true`.
+    Boolean(Conversion<A>),
+}
+
+/// Alleles that can be used with integer and boolean domains.
+pub trait DomainValue: Sized {
+    fn from_i64(value: i64) -> Option<Self>;
+    fn to_i64(&self) -> Option<i64>;
+}
+
+/// The `DomainValue` conversions of an allele type, captured when an integer or boolean domain is built.
+pub struct Conversion<A> {
+    from_i64: fn(i64) -> Option<A>,
+    to_i64: fn(&A) -> Option<i64>,
+}
+
+impl<A: DomainValue> Default for Conversion<A> {
+    fn default() -> Self {
+        Self {
+            from_i64: A::from_i64,
+            to_i64: A::to_i64,
+        }
+    }
+}
+
+impl<A> Clone for Conversion<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for Conversion<A> {}
+
+impl<A> Debug for Conversion<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Conversion")
+    }
+}
+
+/// There is a single conversion per allele type.
+impl<A> PartialEq for Conversion<A> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<A> Eq for Conversion<A> {}
+
+macro_rules! impl_domain_value {
+    ($($t:ty),*) => {
+        $(
+            impl DomainValue for $t {
+                fn from_i64(value: i64) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+
+                fn to_i64(&self) -> Option<i64> {
+                    i64::try_from(*self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_domain_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl DomainValue for bool {
+    fn from_i64(value: i64) -> Option<Self> {
+        match value {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        Some(i64::from(*self))
+    }
+}
+
+impl DomainValue for char {
+    fn from_i64(value: i64) -> Option<Self> {
+        u32::try_from(value).ok().and_then(Self::from_u32)
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        Some(i64::from(u32::from(*self)))
+    }
+}
+
+impl<A: DomainValue> Domain<A> {
+    /// Any integer in `range`.
+    #[must_use]
+    pub fn integer(range: RangeInclusive<i64>) -> Self {
+        Self::Integer(range, Conversion::default())
+    }
+
+    /// Either `0`/`false` or `1`/`true`.
+    #[must_use]
+    pub fn boolean() -> Self {
+        Self::Boolean(Conversion::default())
+    }
+}
+
+impl<A: Clone + PartialEq> Domain<A> {
+    /// A uniformly random value of the domain.
+    ///
+    /// # Panics
+    /// Will panic if the domain is empty or holds integers the allele type cannot represent.
+    #[must_use]
+    pub fn sample(&self) -> A {
+        match self {
+            Self::Integer(range, conversion) => {
+                (conversion.from_i64)(thread_rng().gen_range(range.clone()))
+                    .expect("integer domain must fit the allele type")
+            }
+            Self::Categorical(values) => values
+                .choose(&mut thread_rng())
+                .expect("categorical domain must not be empty")
+                .clone(),
+            Self::Boolean(conversion) => {
+                (conversion.from_i64)(i64::from(thread_rng().gen_bool(0.5)))
+                    .expect("allele type must represent booleans")
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: &A) -> bool {
+        match self {
+            Self::Integer(range, conversion) => {
+                (conversion.to_i64)(value).is_some_and(|v| range.contains(&v))
+            }
+            Self::Categorical(values) => values.contains(value),
+            Self::Boolean(conversion) => matches!((conversion.to_i64)(value), Some(0 | 1)),
+        }
+    }
+
+    /// Move `value` by `step` within the domain: integers are shifted and clamped,
+    /// categorical values move `step` places along the list and booleans flip on odd steps.
+    /// Values outside the domain are resampled.
+    #[must_use]
+    pub fn creep(&self, value: &A, step: i64) -> A {
+        if !self.contains(value) {
+            return self.sample();
+        }
+
+        match self {
+            Self::Integer(range, conversion) => {
+                let shifted = (conversion.to_i64)(value).unwrap().saturating_add(step);
+                (conversion.from_i64)(shifted.clamp(*range.start(), *range.end())).unwrap()
+            }
+            Self::Categorical(values) => {
+                let index = values.iter().position(|v| v == value).unwrap() as i64;
+                let last = values.len() as i64 - 1;
+                values[(index + step).clamp(0, last) as usize].clone()
+            }
+            Self::Boolean(conversion) => {
+                (conversion.from_i64)((conversion.to_i64)(value).unwrap() ^ (step & 1)).unwrap()
+            }
+        }
+    }
+}
+
+/// A genotype of `length` genes, each drawn from its `Problem::gene_domain`.
+///
+/// # Panics
+/// Will panic if a locus has no domain.
+#[must_use]
+pub fn genotype<T: Problem>(length: usize) -> Vec<T::Allele> {
+    (0..length)
+        .map(|locus| {
+            T::gene_domain(locus)
+                .expect("every locus must have a domain")
+                .sample()
+        })
+        .collect()
+}
+
+/// Loci whose gene lies outside its `Problem::gene_domain`.
+pub fn invalid_loci<T: Problem>(genes: &[T::Allele]) -> impl Iterator<Item = usize> + '_ {
+    genes.iter().enumerate().filter_map(|(locus, gene)| {
+        T::gene_domain(locus)
+            .filter(|domain| !domain.contains(gene))
+            .map(|_| locus)
+    })
+}
+
+/// Whether every gene lies within its `Problem::gene_domain`.
+#[must_use]
+pub fn is_valid<T: Problem>(genes: &[T::Allele]) -> bool {
+    invalid_loci::<T>(genes).next().is_none()
+}
+
+/// Repair resampling every gene that lies outside its domain.
+#[derive(Clone, Default, Debug)]
+pub struct Resample<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> Repair<T> for Resample<T> {
+    fn repair(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for locus in invalid_loci::<T>(chromosome.genes()).collect::<Vec<_>>() {
+            chromosome.genes_mut()[locus] = T::gene_domain(locus).unwrap().sample();
+        }
+        chromosome
+    }
+}
@@ -1,19 +1,51 @@
 use crate::chromosome::Chromosome;
+use crate::constraint::{penalize, Penalty, PenaltyCoefficient, Repair};
+use crate::crossover::arithmetic::Arithmetic;
+use crate::crossover::blend_alpha::BlendAlpha;
+use crate::crossover::cut_and_splice::CutAndSplice;
+use crate::crossover::cycle::Cycle;
+use crate::crossover::diagonal::Diagonal;
+use crate::crossover::edge_recombination::EdgeRecombination;
+use crate::crossover::gene_pool::GenePool;
+use crate::crossover::k_point::KPoint;
+use crate::crossover::messy::Messy;
 use crate::crossover::order_one::OrderOne;
+use crate::crossover::partially_mapped::PartiallyMapped;
+use crate::crossover::simulated_binary::SimulatedBinary;
 use crate::crossover::single_point::SinglePoint;
 use crate::crossover::uniform::Uniform;
-use crate::crossover::{Crossover, CrossoverStrategy};
+use crate::crossover::{Crossover, CrossoverStrategy, RealCrossover};
+use crate::distance::Distance;
+use crate::lineage::Lineage;
+use crate::mutation::deletion::Deletion;
+use crate::mutation::insertion::Insertion;
+use crate::mutation::inversion::Inversion;
+use crate::mutation::scramble::Scramble;
+use crate::mutation::shuffle::Shuffle;
+use crate::mutation::swap::Swap;
+use crate::mutation::{Mutation, MutationStrategy};
+use crate::permutation::{Permutation, PermutationCrossover, PermutationMutation};
 use crate::problem::Problem;
+use crate::report::Report;
 use crate::selection::elitism::ElitistSelection;
+use crate::selection::feasibility::{feasibility_order, FeasibilityTournament};
+use crate::selection::lexicase::LexicaseSelection;
 use crate::selection::random::RandomSelection;
 use crate::selection::roulette::RouletteSelection;
+use crate::selection::stochastic_ranking::StochasticRanking;
 use crate::selection::tournament::{TournamentWithDuplicates, TournamentWithoutDuplicates};
 use crate::selection::{Selection, SelectionStrategy};
 use itertools::Itertools;
 use num::cast::AsPrimitive;
-use rand::prelude::SliceRandom;
 use rand::{thread_rng, Rng};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::time::Instant;
+
+type SelectionFactory<T> = Box<dyn Fn() -> Box<dyn SelectionStrategy<T>>>;
+type CrossoverFactory<T> = Box<dyn Fn() -> Box<dyn CrossoverStrategy<T>>>;
+/// Parent groups, selection shortfall and surviving non-parents.
+type Selected<T> = (Vec<Vec<Chromosome<T>>>, usize, Vec<Chromosome<T>>);
 
 pub struct GeneticAlgorithm<T: Problem> {
     population_size: u32,
@@ -21,127 +53,377 @@ pub struct GeneticAlgorithm<T: Problem> {
     fitness_target: Option<T::Fitness>,
     mutation_rate: f32,
     selection_rate: f32,
+    crossover_rate: f64,
     selection_strategy: Box<dyn SelectionStrategy<T>>,
     crossover_strategy: Box<dyn CrossoverStrategy<T>>,
+    mutation_strategy: Box<dyn MutationStrategy<T>>,
+    max_length: Option<usize>,
+    crowding: Option<Box<dyn Distance<T>>>,
+    penalty: Option<Penalty>,
+    repair: Option<Box<dyn Repair<T>>>,
 }
 
 impl<T: Problem + 'static> GeneticAlgorithm<T> {
     pub fn run(&self) -> Chromosome<T> {
-        self.evolve()
+        self.evolve(None).best
+    }
+
+    /// Run and report the evaluations, generations and fitness history along with the best chromosome.
+    pub fn run_with_report(&self) -> Report<T> {
+        self.evolve(None)
+    }
+
+    /// Run while recording the genealogy of every chromosome created, see `Lineage`.
+    pub fn run_with_lineage(&self) -> (Chromosome<T>, Lineage) {
+        let mut lineage = Lineage::default();
+        let report = self.evolve(Some(&mut lineage));
+        (report.best, lineage)
     }
 
-    fn evolve(&self) -> Chromosome<T> {
+    fn evolve(&self, mut lineage: Option<&mut Lineage>) -> Report<T> {
+        let start = Instant::now();
+        let mut evaluations = 0;
+        let mut history = Vec::new();
+        let mut shortfalls = Vec::new();
+
         let mut population = (0..self.population_size)
             .map(|_| Chromosome::new(T::genotype()))
             .collect_vec();
+        if let Some(lineage) = lineage.as_deref_mut() {
+            for c in &population {
+                lineage.record(c.id(), Vec::new(), None, 0);
+            }
+        }
 
         let mut generation = 0;
         let mut last_max_fitness = 0.0;
         let mut temperature: f64 = 0.0;
 
+        let mut penalty = self.penalty.map(PenaltyCoefficient::new);
+        let coefficient = |penalty: &Option<PenaltyCoefficient>| {
+            penalty.as_ref().map_or(0.0, PenaltyCoefficient::value)
+        };
+
         let n = (population.len() as f32 * self.selection_rate).round() as usize;
-        let n = if n % 2 == 0 { n } else { n + 1 };
         loop {
-            population = self.evaluate(population);
+            population = self.evaluate(population, coefficient(&penalty), &mut evaluations);
+            if let Some(lineage) = lineage.as_deref_mut() {
+                for c in &population {
+                    lineage.set_fitness(c.id(), c.get_fitness().as_());
+                }
+            }
 
             let best = population.first().unwrap();
             let best_fitness = best.get_fitness();
+            history.push(best_fitness);
 
             temperature = 0.8 * (temperature + (best_fitness.as_() - last_max_fitness));
 
             if generation % 1000 == 0 {
-                println!("Current best: {best_fitness:?} ({})", best.genes.len());
+                println!("Current best: {best_fitness:?} ({})", best.genes().len());
             }
             if generation % 10_000 == 0 {
-                println!("{:?}", best.genes.iter().map(ToString::to_string).join(""));
+                println!(
+                    "{:?}",
+                    best.genes().iter().map(ToString::to_string).join("")
+                );
             }
 
             if self.problem.terminate(&population, generation, temperature) {
-                return best.clone();
+                if let Some(lineage) = lineage {
+                    lineage.set_best(best.id());
+                }
+                return Report {
+                    best: best.clone(),
+                    generations: generation,
+                    evaluations,
+                    restarts: 0,
+                    history,
+                    shortfalls,
+                    elapsed: start.elapsed(),
+                };
             }
 
-            let (parents, mut leftover) = self.selection(population, n);
-
-            population = self.crossover(parents);
+            debug_assert!(
+                population.iter().all(Chromosome::is_evaluated),
+                "every chromosome must be evaluated before selection"
+            );
+            let best_feasible = best.is_feasible();
+            // Offspring are evaluated, and belong to, the next generation
+            let born = generation + 1;
+
+            let (parents, shortfall, mut leftover) = self.selection(
+                population,
+                n,
+                coefficient(&penalty),
+                &mut evaluations,
+                born,
+                lineage.as_deref_mut(),
+            );
+            shortfalls.push(shortfall);
+            let (offspring, mut survivors) = self.crossover(
+                parents,
+                coefficient(&penalty),
+                &mut evaluations,
+                born,
+                lineage.as_deref_mut(),
+            );
+            population = offspring;
             population.append(&mut leftover);
-            while population.len() < self.population_size as usize {
-                population.push(Chromosome::new(T::genotype()));
+            while population.len() + survivors.len() < self.population_size as usize {
+                let immigrant = Chromosome::new(T::genotype());
+                if let Some(lineage) = lineage.as_deref_mut() {
+                    lineage.record(immigrant.id(), Vec::new(), Some("genotype"), born);
+                }
+                population.push(immigrant);
             }
 
-            population = Self::mutate(population, self.mutation_rate);
+            population = self.mutate(population, born, lineage.as_deref_mut());
+            // Crowded survivors were mutated before competing, and enter as they won
+            population.append(&mut survivors);
+            if penalty.as_mut().is_some_and(|p| p.update(best_feasible)) {
+                population
+                    .iter_mut()
+                    .for_each(Chromosome::invalidate_fitness);
+            }
 
             last_max_fitness = best_fitness.as_();
             generation += 1;
         }
     }
 
-    fn evaluate(&self, p: Vec<Chromosome<T>>) -> Vec<Chromosome<T>> {
+    fn evaluate(
+        &self,
+        p: Vec<Chromosome<T>>,
+        penalty: f64,
+        evaluations: &mut usize,
+    ) -> Vec<Chromosome<T>> {
         p.into_iter()
-            .map(|mut c| {
-                c.set_fitness(self.problem.fitness(&c));
+            .map(|c| {
+                let mut c = self.evaluate_one(c, penalty, evaluations);
                 c.age += 1;
                 c
             })
-            .sorted_by_key(Chromosome::get_fitness)
+            .sorted_by(feasibility_order)
             .rev()
             .collect()
     }
 
+    /// Repair, evaluate and penalize a chromosome, unless its fitness is already known.
+    /// Calls to `Problem::fitness` are counted in `evaluations`.
+    fn evaluate_one(
+        &self,
+        mut c: Chromosome<T>,
+        penalty: f64,
+        evaluations: &mut usize,
+    ) -> Chromosome<T> {
+        if c.is_evaluated() {
+            return c;
+        }
+        if let Some(repair) = &self.repair {
+            c = repair.repair(c);
+        }
+
+        *evaluations += 1;
+        let violation = self.problem.violation(&c);
+        c.set_fitness(penalize(self.problem.fitness(&c), violation, penalty));
+        c.set_violation(violation);
+        if let Some(case_errors) = self.problem.case_errors(&c) {
+            c.set_case_errors(case_errors);
+        }
+        c
+    }
+
+    /// Select `n` parents grouped for crossover, and the rest of the population surviving as is.
+    /// Also returns the selection shortfall, see `SelectionStrategy::select_with_shortfall`.
     fn selection(
         &self,
         p: Vec<Chromosome<T>>,
         n: usize,
-    ) -> (
-        Vec<Option<(Chromosome<T>, Chromosome<T>)>>,
-        Vec<Chromosome<T>>,
-    ) {
-        let parents = self.selection_strategy.select(&p, n);
+        penalty: f64,
+        evaluations: &mut usize,
+        generation: u32,
+        mut lineage: Option<&mut Lineage>,
+    ) -> Selected<T> {
+        let (parents, shortfall) = self.selection_strategy.select_with_shortfall(&p, n);
+
+        // Parents created by the selection fallback rather than picked from the population,
+        // like random immigrants, are evaluated before anything reads their fitness
+        let parents = parents
+            .into_iter()
+            .map(|c| {
+                if c.is_evaluated() {
+                    return c;
+                }
+                if let Some(lineage) = lineage.as_deref_mut() {
+                    lineage.record(c.id(), Vec::new(), Some("genotype"), generation);
+                }
+                let c = self.evaluate_one(c, penalty, evaluations);
+                if let Some(lineage) = lineage.as_deref_mut() {
+                    lineage.set_fitness(c.id(), c.get_fitness().as_());
+                }
+                c
+            })
+            .collect_vec();
 
         let population_set: HashSet<_> = p.into_iter().collect();
         let parents_set: HashSet<_> = parents.iter().cloned().collect();
 
         let leftover: Vec<_> = population_set
             .difference(&parents_set)
-            .take(self.population_size as usize - parents.len())
+            .take((self.population_size as usize).saturating_sub(parents.len()))
             .cloned()
             .collect();
 
         let parents: Vec<_> = parents
             .into_iter()
-            .chunks(2)
+            .chunks(self.crossover_strategy.arity())
             .into_iter()
-            .map(Itertools::collect_tuple)
+            .map(Iterator::collect)
             .collect();
 
-        (parents, leftover)
+        (parents, shortfall, leftover)
     }
 
-    fn crossover(&self, g: Vec<Option<(Chromosome<T>, Chromosome<T>)>>) -> Vec<Chromosome<T>> {
-        let length = g.len();
+    /// Recombine the parent groups. Returns the offspring, still to be mutated, and
+    /// the survivors of deterministic crowding, which are already mutated and evaluated.
+    fn crossover(
+        &self,
+        g: Vec<Vec<Chromosome<T>>>,
+        penalty: f64,
+        evaluations: &mut usize,
+        generation: u32,
+        mut lineage: Option<&mut Lineage>,
+    ) -> (Vec<Chromosome<T>>, Vec<Chromosome<T>>) {
+        let length = g.iter().map(Vec::len).sum();
+        let arity = self.crossover_strategy.arity();
+
+        g.into_iter().fold(
+            (Vec::with_capacity(length), Vec::new()),
+            |(mut offspring, mut survivors), mut parents| {
+                if parents.len() < arity || !thread_rng().gen_bool(self.crossover_rate) {
+                    offspring.append(&mut parents);
+                    return (offspring, survivors);
+                }
 
-        g.into_iter()
-            .fold(Vec::with_capacity(length * 2), |mut acc, t| {
-                if let Some((mut father, mut mother)) = t {
-                    let (father, mother) = self.crossover_strategy.crossover(father, mother);
+                let ids = parents.iter().map(Chromosome::id).collect_vec();
+                let crowding = self
+                    .crowding
+                    .as_ref()
+                    .map(|metric| (metric, parents.clone()));
+                let children = self.crossover_strategy.recombine(parents);
+
+                if let Some(lineage) = lineage.as_deref_mut() {
+                    // Parents passed through unchanged keep their record
+                    for child in children.iter().filter(|c| !ids.contains(&c.id())) {
+                        let operator = Some(self.crossover_strategy.name());
+                        lineage.record(child.id(), ids.clone(), operator, generation);
+                    }
+                }
 
-                    acc.push(father);
-                    acc.push(mother);
+                match crowding {
+                    Some((metric, parents)) => {
+                        // Children compete as they will enter the population
+                        let children = children
+                            .into_iter()
+                            .map(|c| self.vary(c, generation, lineage.as_deref_mut()))
+                            .collect();
+                        survivors.extend(self.crowd(
+                            metric.as_ref(),
+                            parents,
+                            children,
+                            penalty,
+                            evaluations,
+                        ));
+                    }
+                    None => offspring.extend(children),
                 }
+                (offspring, survivors)
+            },
+        )
+    }
 
-                acc
-            })
+    /// Deterministic crowding: every child competes with the parent it is most similar to,
+    /// and the fitter of the two survives (the child wins ties).
+    /// Children and parents are matched greedily, closest pairs first;
+    /// parents left without a child survive unchallenged.
+    fn crowd(
+        &self,
+        metric: &dyn Distance<T>,
+        parents: Vec<Chromosome<T>>,
+        children: Vec<Chromosome<T>>,
+        penalty: f64,
+        evaluations: &mut usize,
+    ) -> Vec<Chromosome<T>> {
+        let children = children
+            .into_iter()
+            .map(|c| self.evaluate_one(c, penalty, evaluations))
+            .collect_vec();
+
+        let pairs = (0..parents.len())
+            .cartesian_product(0..children.len())
+            .map(|(p, c)| (metric.distance(&parents[p], &children[c]), p, c))
+            .sorted_by(|x, y| x.0.total_cmp(&y.0));
+        let mut opponent = vec![None; parents.len()];
+        let mut matched = vec![false; children.len()];
+        for (_, p, c) in pairs {
+            if opponent[p].is_none() && !matched[c] {
+                opponent[p] = Some(c);
+                matched[c] = true;
+            }
+        }
+
+        let mut children = children.into_iter().map(Some).collect_vec();
+        parents
+            .into_iter()
+            .zip(opponent)
+            .map(
+                |(parent, opponent)| match opponent.and_then(|c| children[c].take()) {
+                    Some(child) if child.get_fitness() >= parent.get_fitness() => child,
+                    _ => parent,
+                },
+            )
+            .collect()
     }
 
-    fn mutate(p: Vec<Chromosome<T>>, mutation_rate: f32) -> Vec<Chromosome<T>> {
+    fn mutate(
+        &self,
+        p: Vec<Chromosome<T>>,
+        generation: u32,
+        mut lineage: Option<&mut Lineage>,
+    ) -> Vec<Chromosome<T>> {
         p.into_iter()
-            .map(|mut g| {
-                if rand::random::<f32>() <= mutation_rate {
-                    g.genes.shuffle(&mut thread_rng());
-                }
-                g
-            })
+            .map(|g| self.vary(g, generation, lineage.as_deref_mut()))
             .collect()
     }
+
+    /// Mutate a chromosome with probability `mutation_rate`, then truncate it to `max_length`.
+    fn vary(
+        &self,
+        mut g: Chromosome<T>,
+        generation: u32,
+        mut lineage: Option<&mut Lineage>,
+    ) -> Chromosome<T> {
+        if rand::random::<f32>() <= self.mutation_rate {
+            let parent = g.id();
+            g = self.mutation_strategy.mutate(g);
+            g.invalidate_fitness();
+            g.renew_id();
+            if let Some(lineage) = lineage.as_deref_mut() {
+                let operator = Some(self.mutation_strategy.name());
+                lineage.record(g.id(), vec![parent], operator, generation);
+            }
+        }
+        if let Some(max_length) = self.max_length.filter(|&l| g.genes().len() > l) {
+            let parent = g.id();
+            g.genes_mut().truncate(max_length);
+            g.renew_id();
+            if let Some(lineage) = lineage {
+                lineage.record(g.id(), vec![parent], Some("truncation"), generation);
+            }
+        }
+        g
+    }
 }
 
 pub struct GeneticBuilder<T: Problem> {
@@ -149,10 +431,18 @@ pub struct GeneticBuilder<T: Problem> {
     problem: Option<T>,
     selection_strategy: Option<Box<dyn SelectionStrategy<T>>>,
     crossover_strategy: Option<Box<dyn CrossoverStrategy<T>>>,
+    mutation_strategy: Option<Box<dyn MutationStrategy<T>>>,
+    max_length: Option<usize>,
+    crowding: Option<Box<dyn Distance<T>>>,
+    penalty: Option<Penalty>,
+    repair: Option<Box<dyn Repair<T>>>,
+    named_selections: HashMap<String, SelectionFactory<T>>,
+    named_crossovers: HashMap<String, CrossoverFactory<T>>,
 
     population_size: u32,
     mutation_rate: f32,
     selection_rate: f32,
+    crossover_rate: f64,
 }
 
 impl<T: Problem + 'static> GeneticBuilder<T> {
@@ -191,6 +481,22 @@ impl<T: Problem + 'static> GeneticBuilder<T> {
         self
     }
 
+    /// Probability with which a pair of parents is recombined.
+    /// Pairs that are not recombined pass into the next generation unchanged,
+    /// keeping their fitness.
+    ///
+    /// # Panics
+    /// Will panic if `crossover_rate` is not between 0 and 1.
+    #[must_use]
+    pub fn with_crossover_rate(mut self, crossover_rate: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&crossover_rate),
+            "crossover rate must be between 0 and 1"
+        );
+        self.crossover_rate = crossover_rate;
+        self
+    }
+
     #[must_use]
     #[allow(clippy::box_default)]
     pub fn with_selection_strategy(mut self, selection_strategy: Selection) -> Self {
@@ -198,12 +504,16 @@ impl<T: Problem + 'static> GeneticBuilder<T> {
             Selection::TournamentWithDuplicates => {
                 Some(Box::new(TournamentWithDuplicates::default()))
             }
-            Selection::TournamentWithoutDuplicates => {
-                Some(Box::new(TournamentWithoutDuplicates::default()))
+            Selection::TournamentWithoutDuplicates(fallback) => {
+                Some(Box::new(TournamentWithoutDuplicates::new(fallback)))
             }
             Selection::Roulette => Some(Box::new(RouletteSelection::default())),
             Selection::Elitism => Some(Box::new(ElitistSelection::default())),
             Selection::Random => Some(Box::new(RandomSelection::default())),
+            Selection::Lexicase => Some(Box::new(LexicaseSelection::default())),
+            Selection::EpsilonLexicase => Some(Box::new(LexicaseSelection::epsilon())),
+            Selection::FeasibilityRules => Some(Box::new(FeasibilityTournament::default())),
+            Selection::StochasticRanking => Some(Box::new(StochasticRanking::default())),
         };
 
         self
@@ -213,14 +523,146 @@ impl<T: Problem + 'static> GeneticBuilder<T> {
     #[allow(clippy::box_default)]
     pub fn with_crossover_strategy(mut self, crossover_strategy: Crossover) -> Self {
         self.crossover_strategy = match crossover_strategy {
-            Crossover::OrderOne => Some(Box::new(OrderOne::default())),
             Crossover::Uniform(crossover_rate) => Some(Box::new(Uniform::new(crossover_rate))),
             Crossover::SinglePoint => Some(Box::new(SinglePoint::default())),
+            Crossover::TwoPoint => Some(Box::new(KPoint::new(2))),
+            Crossover::KPoint(k) => Some(Box::new(KPoint::new(k))),
+            Crossover::Diagonal(parents) => Some(Box::new(Diagonal::new(parents))),
+            Crossover::GenePool(parents) => Some(Box::new(GenePool::new(parents))),
+            Crossover::CutAndSplice => Some(Box::new(CutAndSplice::default())),
+            Crossover::Messy(cut_rate) => Some(Box::new(Messy::new(cut_rate))),
+        };
+
+        self
+    }
+
+    /// Mutation applied to chromosomes picked with `mutation_rate`, shuffling their genes by default.
+    #[must_use]
+    #[allow(clippy::box_default)]
+    pub fn with_mutation_strategy(mut self, mutation_strategy: Mutation) -> Self {
+        self.mutation_strategy = match mutation_strategy {
+            Mutation::Shuffle => Some(Box::new(Shuffle::default())),
+            Mutation::Insertion => Some(Box::new(Insertion::default())),
+            Mutation::Deletion => Some(Box::new(Deletion::default())),
         };
 
         self
     }
 
+    /// Longest chromosome allowed in the population.
+    /// Chromosomes grown past it by crossover or mutation are truncated.
+    #[must_use]
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Use a user-defined selection operator instead of one of the built-in `Selection` variants.
+    #[must_use]
+    pub fn with_custom_selection(
+        mut self,
+        selection_strategy: impl SelectionStrategy<T> + 'static,
+    ) -> Self {
+        self.selection_strategy = Some(Box::new(selection_strategy));
+        self
+    }
+
+    /// Use a user-defined crossover operator instead of one of the built-in `Crossover` variants.
+    #[must_use]
+    pub fn with_custom_crossover(
+        mut self,
+        crossover_strategy: impl CrossoverStrategy<T> + 'static,
+    ) -> Self {
+        self.crossover_strategy = Some(Box::new(crossover_strategy));
+        self
+    }
+
+    /// Replace parents with their offspring through deterministic crowding,
+    /// pairing every child with its most similar parent according to `metric`.
+    #[must_use]
+    pub fn with_deterministic_crowding(mut self, metric: impl Distance<T> + 'static) -> Self {
+        self.crowding = Some(Box::new(metric));
+        self
+    }
+
+    /// Use a user-defined mutation operator instead of one of the built-in `Mutation` variants.
+    #[must_use]
+    pub fn with_custom_mutation(
+        mut self,
+        mutation_strategy: impl MutationStrategy<T> + 'static,
+    ) -> Self {
+        self.mutation_strategy = Some(Box::new(mutation_strategy));
+        self
+    }
+
+    /// Subtract a penalty proportional to `Problem::violation` from the fitness of infeasible chromosomes.
+    #[must_use]
+    pub const fn with_penalty(mut self, penalty: Penalty) -> Self {
+        self.penalty = Some(penalty);
+        self
+    }
+
+    /// Repair offspring before they are evaluated.
+    #[must_use]
+    pub fn with_repair(mut self, repair: impl Repair<T> + 'static) -> Self {
+        self.repair = Some(Box::new(repair));
+        self
+    }
+
+    /// Register a selection operator under `name`, so it can later be picked with
+    /// `with_named_selection`, e.g. from a configuration file.
+    #[must_use]
+    pub fn register_selection<S, F>(mut self, name: impl Into<String>, factory: F) -> Self
+    where
+        S: SelectionStrategy<T> + 'static,
+        F: Fn() -> S + 'static,
+    {
+        self.named_selections
+            .insert(name.into(), Box::new(move || Box::new(factory())));
+        self
+    }
+
+    /// Register a crossover operator under `name`, so it can later be picked with
+    /// `with_named_crossover`, e.g. from a configuration file.
+    #[must_use]
+    pub fn register_crossover<C, F>(mut self, name: impl Into<String>, factory: F) -> Self
+    where
+        C: CrossoverStrategy<T> + 'static,
+        F: Fn() -> C + 'static,
+    {
+        self.named_crossovers
+            .insert(name.into(), Box::new(move || Box::new(factory())));
+        self
+    }
+
+    /// Use a selection operator previously registered with `register_selection`.
+    ///
+    /// # Panics
+    /// Will panic if no selection operator was registered under `name`.
+    #[must_use]
+    pub fn with_named_selection(mut self, name: &str) -> Self {
+        let factory = self
+            .named_selections
+            .get(name)
+            .unwrap_or_else(|| panic!("unknown selection strategy: {name}"));
+        self.selection_strategy = Some(factory());
+        self
+    }
+
+    /// Use a crossover operator previously registered with `register_crossover`.
+    ///
+    /// # Panics
+    /// Will panic if no crossover operator was registered under `name`.
+    #[must_use]
+    pub fn with_named_crossover(mut self, name: &str) -> Self {
+        let factory = self
+            .named_crossovers
+            .get(name)
+            .unwrap_or_else(|| panic!("unknown crossover strategy: {name}"));
+        self.crossover_strategy = Some(factory());
+        self
+    }
+
     /// Build a `GeneticAlgorithm`
     ///
     /// # Panics
@@ -241,15 +683,69 @@ impl<T: Problem + 'static> GeneticBuilder<T> {
             problem: self.problem.unwrap(),
             selection_strategy: self.selection_strategy.unwrap(),
             crossover_strategy: self.crossover_strategy.unwrap(),
+            mutation_strategy: self
+                .mutation_strategy
+                .unwrap_or_else(|| Box::new(Shuffle::default())),
+            max_length: self.max_length,
+            crowding: self.crowding,
+            penalty: self.penalty,
+            repair: self.repair,
 
             fitness_target: self.fitness_target,
             population_size: self.population_size,
             mutation_rate: self.mutation_rate,
             selection_rate: self.selection_rate,
+            crossover_rate: self.crossover_rate,
         }
     }
 }
 
+impl<T: Problem + 'static> GeneticBuilder<T>
+where
+    T::Allele: AsPrimitive<f64>,
+    f64: AsPrimitive<T::Allele>,
+{
+    #[must_use]
+    pub fn with_real_crossover_strategy(mut self, crossover_strategy: RealCrossover) -> Self {
+        self.crossover_strategy = match crossover_strategy {
+            RealCrossover::Arithmetic => Some(Box::new(Arithmetic::default())),
+            RealCrossover::BlendAlpha(alpha) => Some(Box::new(BlendAlpha::new(alpha))),
+            RealCrossover::SimulatedBinary(eta) => Some(Box::new(SimulatedBinary::new(eta))),
+        };
+
+        self
+    }
+}
+
+impl<T, A> GeneticBuilder<T>
+where
+    T: Problem<Allele = Permutation<A>> + 'static,
+    A: Eq + Hash + Clone + 'static,
+{
+    #[must_use]
+    pub fn with_permutation_crossover(mut self, crossover_strategy: PermutationCrossover) -> Self {
+        self.crossover_strategy = match crossover_strategy {
+            PermutationCrossover::OrderOne => Some(Box::new(OrderOne::default())),
+            PermutationCrossover::PartiallyMapped => Some(Box::new(PartiallyMapped::default())),
+            PermutationCrossover::Cycle => Some(Box::new(Cycle::default())),
+            PermutationCrossover::EdgeRecombination => Some(Box::new(EdgeRecombination::default())),
+        };
+
+        self
+    }
+
+    #[must_use]
+    pub fn with_permutation_mutation(mut self, mutation_strategy: PermutationMutation) -> Self {
+        self.mutation_strategy = match mutation_strategy {
+            PermutationMutation::Swap => Some(Box::new(Swap::default())),
+            PermutationMutation::Inversion => Some(Box::new(Inversion::default())),
+            PermutationMutation::Scramble => Some(Box::new(Scramble::default())),
+        };
+
+        self
+    }
+}
+
 impl<T: Problem> Default for GeneticBuilder<T> {
     fn default() -> Self {
         Self {
@@ -257,10 +753,92 @@ impl<T: Problem> Default for GeneticBuilder<T> {
             problem: None,
             selection_strategy: None,
             crossover_strategy: None,
+            mutation_strategy: None,
+            max_length: None,
+            crowding: None,
+            penalty: None,
+            repair: None,
+            named_selections: HashMap::new(),
+            named_crossovers: HashMap::new(),
 
             population_size: 100,
             mutation_rate: 0.05,
             selection_rate: 0.8,
+            crossover_rate: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distance::Hamming;
+
+    const LENGTH: usize = 16;
+
+    /// Two optima as far apart as possible: all genes set, or none.
+    #[derive(Eq, PartialEq, Hash, Default, Clone, Debug)]
+    struct TwoPeaks;
+    impl TwoPeaks {
+        fn peak(c: &Chromosome<Self>) -> Option<bool> {
+            let set = c.genes().iter().filter(|&&g| g).count();
+            match set {
+                0 => Some(false),
+                LENGTH => Some(true),
+                _ => None,
+            }
+        }
+    }
+    impl Problem for TwoPeaks {
+        type Fitness = i32;
+        type Allele = bool;
+
+        fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness {
+            let set = chromosome.genes().iter().filter(|&&g| g).count();
+            set.max(LENGTH - set) as i32
         }
+
+        fn terminate(&self, population: &[Chromosome<Self>], generation: u32, _: f64) -> bool {
+            let peaks = population.iter().map(Self::peak).collect_vec();
+            assert!(
+                peaks.iter().all(Option::is_some),
+                "chromosome off the peaks in generation {generation}"
+            );
+            assert!(
+                peaks.contains(&Some(false)) && peaks.contains(&Some(true)),
+                "peak lost in generation {generation}"
+            );
+            generation == 100
+        }
+
+        fn genotype() -> Vec<Self::Allele> {
+            vec![thread_rng().gen_bool(0.5); LENGTH]
+        }
+    }
+
+    /// Flips a single random gene.
+    struct FlipOne;
+    impl MutationStrategy<TwoPeaks> for FlipOne {
+        fn mutate(&self, mut chromosome: Chromosome<TwoPeaks>) -> Chromosome<TwoPeaks> {
+            let locus = thread_rng().gen_range(0..LENGTH);
+            chromosome.genes_mut()[locus] ^= true;
+            chromosome
+        }
+    }
+
+    #[test]
+    fn deterministic_crowding_keeps_distant_optima() {
+        let ga = GeneticBuilder::new()
+            .with_problem(TwoPeaks)
+            .with_population_size(40)
+            .with_selection_rate(1.0)
+            .with_selection_strategy(Selection::Random)
+            .with_crossover_strategy(Crossover::Uniform(0.5))
+            .with_custom_mutation(FlipOne)
+            .with_mutation_rate(1.0)
+            .with_deterministic_crowding(Hamming)
+            .build();
+
+        assert_eq!(ga.run_with_report().generations, 100);
     }
 }
@@ -0,0 +1,163 @@
+//! Structured, heterogeneous genomes.
+//!
+//! A struct deriving `Genome` is used as a single allele holding the whole solution,
+//! its fields mixing booleans, integer ranges, bounded reals, enums and nested genomes:
+//!
+//! ```ignore
+//! #[derive(Genome, Clone, PartialEq, Eq, Hash, Debug)]
+//! struct Config {
+//!     #[genome(range = 1..=64)]
+//!     threads: u32,
+//!     #[genome(bounds = (0.0, 1.0), sigma = 0.05)]
+//!     ratio: Real,
+//!     #[genome(choices = ["lz4", "zstd"])]
+//!     codec: &'static str,
+//!     // Fields without attributes must implement `Genome` themselves, like `bool` or a derived enum
+//!     cache: bool,
+//!     level: Level,
+//! }
+//! ```
+//!
+//! Field attributes:
+//! - `range = <range>`: a uniformly drawn integer, reset when mutated.
+//! - `bounds = (<low>, <high>)`: a uniformly drawn `Real`, reset when mutated,
+//!   or perturbed by Gaussian noise and clamped when `sigma = <value>` is given.
+//! - `choices = [<values>]`: one of the listed values, reset when mutated.
+//!
+//! Deriving `Genome` on an enum with unit variants picks one of them.
+//!
+//! The problem then uses `Allele = Config`, a genotype of `vec![Config::random()]`,
+//! `GenomeCrossover` and `GenomeMutation`.
+
+use crate::chromosome::Chromosome;
+use crate::crossover::CrossoverStrategy;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+pub use genetic_algorithm_derive::Genome;
+
+pub trait Genome: Sized + Clone {
+    /// A random value, within the declared bounds and domains.
+    fn random() -> Self;
+    /// Recombine field by field: plain fields are swapped between the children with probability 0.5,
+    /// nested genomes are recombined recursively.
+    fn crossover(&self, other: &Self) -> (Self, Self);
+    /// Mutate every field independently with probability `rate`.
+    fn mutate(&mut self, rate: f64);
+}
+
+impl Genome for bool {
+    fn random() -> Self {
+        thread_rng().gen()
+    }
+
+    fn crossover(&self, other: &Self) -> (Self, Self) {
+        if thread_rng().gen_bool(0.5) {
+            (*other, *self)
+        } else {
+            (*self, *other)
+        }
+    }
+
+    fn mutate(&mut self, rate: f64) {
+        if thread_rng().gen_bool(rate) {
+            *self = !*self;
+        }
+    }
+}
+
+/// Recombines the structured alleles of both parents pairwise with `Genome::crossover`.
+#[derive(Clone, Default, Debug)]
+pub struct GenomeCrossover<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> CrossoverStrategy<T> for GenomeCrossover<T>
+where
+    T::Allele: Genome,
+{
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        let (son, daughter) = father
+            .genes()
+            .iter()
+            .zip(mother.genes())
+            .map(|(a, b)| a.crossover(b))
+            .unzip();
+        (Chromosome::new(son), Chromosome::new(daughter))
+    }
+}
+
+/// Mutates every field of the structured alleles with probability `field_rate`.
+#[derive(Clone, Debug)]
+pub struct GenomeMutation<T: Problem> {
+    _problem: PhantomData<T>,
+    field_rate: f64,
+}
+
+impl<T: Problem> GenomeMutation<T> {
+    #[must_use]
+    pub fn new(field_rate: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            field_rate,
+        }
+    }
+}
+
+impl<T: Problem> MutationStrategy<T> for GenomeMutation<T>
+where
+    T::Allele: Genome,
+{
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for gene in chromosome.genes_mut() {
+            gene.mutate(self.field_rate);
+        }
+        chromosome
+    }
+}
+
+/// Helpers called by the code `#[derive(Genome)]` generates.
+#[doc(hidden)]
+pub mod __private {
+    use crate::mutation::gaussian::standard_normal;
+    use crate::real::Real;
+    use rand::distributions::uniform::{SampleRange, SampleUniform};
+    use rand::prelude::SliceRandom;
+    use rand::{thread_rng, Rng};
+
+    pub fn coin(probability: f64) -> bool {
+        thread_rng().gen_bool(probability)
+    }
+
+    pub fn sample_range<A: SampleUniform, R: SampleRange<A>>(range: R) -> A {
+        thread_rng().gen_range(range)
+    }
+
+    pub fn sample_bounds((low, high): (f64, f64)) -> Real {
+        Real(thread_rng().gen_range(low..=high))
+    }
+
+    /// `value` plus Gaussian noise of standard deviation `sigma`, clamped to the bounds.
+    pub fn perturb(value: Real, (low, high): (f64, f64), sigma: f64) -> Real {
+        Real((value.0 + sigma * standard_normal()).clamp(low, high))
+    }
+
+    pub fn choose<A: Clone>(choices: &[A]) -> A {
+        choices
+            .choose(&mut thread_rng())
+            .expect("choices must not be empty")
+            .clone()
+    }
+
+    pub fn swap<A>(a: &mut A, b: &mut A) {
+        if coin(0.5) {
+            std::mem::swap(a, b);
+        }
+    }
+}
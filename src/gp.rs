@@ -0,0 +1,190 @@
+//! Tree-based genetic programming.
+//!
+//! Programs are typed expression trees over a user-defined `PrimitiveSet`, checked by `PrimitiveSet::build`.
+//! A `Problem` evolves them by using `Tree` as its allele, usually with a genotype holding a
+//! single tree created by `init::ramped_half_and_half`, and the operators of this module in
+//! place of the linear crossovers and mutations.
+//! Every existing `SelectionStrategy` works unchanged.
+
+pub mod hoist_mutation;
+pub mod init;
+pub mod parsimony;
+pub mod point_mutation;
+pub mod subtree_crossover;
+pub mod subtree_mutation;
+pub mod tree;
+
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
+
+/// Name of a value type in a strongly typed primitive set, such as `"float"` or `"bool"`.
+pub type Type = &'static str;
+
+/// A function node: its return type, the types of its arguments and how to evaluate it.
+#[derive(Clone)]
+pub struct Function<V> {
+    pub name: &'static str,
+    pub ty: Type,
+    pub args: Vec<Type>,
+    pub eval: fn(&[V]) -> V,
+}
+
+/// A leaf node.
+#[derive(Clone)]
+pub enum TerminalKind<V> {
+    /// The input at the given index, bound when the tree is evaluated.
+    Variable(usize),
+    /// A fixed value.
+    Constant(V),
+    /// A constant drawn once, when the node is created.
+    Ephemeral(fn() -> V),
+}
+
+#[derive(Clone)]
+pub struct Terminal<V> {
+    pub name: &'static str,
+    pub ty: Type,
+    pub kind: TerminalKind<V>,
+}
+
+/// Functions and terminals trees are built from, and the type trees must evaluate to.
+#[derive(Clone)]
+pub struct PrimitiveSet<V> {
+    pub root: Type,
+    pub functions: Vec<Function<V>>,
+    pub terminals: Vec<Terminal<V>>,
+}
+
+impl<V> PrimitiveSet<V> {
+    #[must_use]
+    pub const fn new(root: Type) -> Self {
+        Self {
+            root,
+            functions: Vec::new(),
+            terminals: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn function(
+        mut self,
+        name: &'static str,
+        ty: Type,
+        args: &[Type],
+        eval: fn(&[V]) -> V,
+    ) -> Self {
+        self.functions.push(Function {
+            name,
+            ty,
+            args: args.to_vec(),
+            eval,
+        });
+        self
+    }
+
+    #[must_use]
+    pub fn variable(self, name: &'static str, ty: Type, index: usize) -> Self {
+        self.terminal(name, ty, TerminalKind::Variable(index))
+    }
+
+    #[must_use]
+    pub fn constant(self, name: &'static str, ty: Type, value: V) -> Self {
+        self.terminal(name, ty, TerminalKind::Constant(value))
+    }
+
+    #[must_use]
+    pub fn ephemeral(self, name: &'static str, ty: Type, generator: fn() -> V) -> Self {
+        self.terminal(name, ty, TerminalKind::Ephemeral(generator))
+    }
+
+    fn terminal(mut self, name: &'static str, ty: Type, kind: TerminalKind<V>) -> Self {
+        self.terminals.push(Terminal { name, ty, kind });
+        self
+    }
+
+    /// Finish the set, checking that trees of the root type can always be completed:
+    /// every type reachable from the root through function arguments needs a terminal,
+    /// or tree generation could recurse without end.
+    ///
+    /// # Errors
+    /// Returns `PrimitiveSetError::NoTerminal` with the first such type lacking a terminal.
+    pub fn build(self) -> Result<Arc<Self>, PrimitiveSetError> {
+        let mut reachable = BTreeSet::from([self.root]);
+        let mut pending = vec![self.root];
+        while let Some(ty) = pending.pop() {
+            if self.terminals_of(ty).next().is_none() {
+                return Err(PrimitiveSetError::NoTerminal(ty));
+            }
+            for f in self.functions_of(ty) {
+                for &arg in &self.functions[f].args {
+                    if reachable.insert(arg) {
+                        pending.push(arg);
+                    }
+                }
+            }
+        }
+
+        Ok(Arc::new(self))
+    }
+
+    pub(crate) fn functions_of(&self, ty: Type) -> impl Iterator<Item = usize> + '_ {
+        (0..self.functions.len()).filter(move |&f| self.functions[f].ty == ty)
+    }
+
+    pub(crate) fn terminals_of(&self, ty: Type) -> impl Iterator<Item = usize> + '_ {
+        (0..self.terminals.len()).filter(move |&t| self.terminals[t].ty == ty)
+    }
+}
+
+impl<V> Debug for PrimitiveSet<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrimitiveSet")
+            .field("root", &self.root)
+            .field(
+                "functions",
+                &self.functions.iter().map(|p| p.name).collect::<Vec<_>>(),
+            )
+            .field(
+                "terminals",
+                &self.terminals.iter().map(|p| p.name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PrimitiveSetError {
+    /// A type reachable from the root has no terminal.
+    NoTerminal(Type),
+}
+
+impl Display for PrimitiveSetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoTerminal(ty) => write!(
+                f,
+                "type {ty} is reachable from the root but has no terminal"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PrimitiveSetError {}
+
+/// Size limits enforced by the genetic operators to control bloat:
+/// offspring exceeding them are discarded in favour of their parent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Limits {
+    pub max_depth: usize,
+    pub max_size: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_depth: 17,
+            max_size: 512,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::chromosome::Chromosome;
+use crate::gp::tree::Tree;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Replaces every tree with one of its own subtrees of the root type.
+/// The result is never larger than the original, which makes this a bloat-control operator.
+#[derive(Clone, Default, Debug)]
+pub struct HoistMutation<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<V, T> MutationStrategy<T> for HoistMutation<T>
+where
+    V: Clone + Eq + Hash + Display,
+    T: Problem<Allele = Tree<V>>,
+{
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for tree in chromosome.genes_mut() {
+            let root = tree.type_at(0);
+            let candidates: Vec<usize> = (0..tree.size())
+                .filter(|&i| tree.type_at(i) == root)
+                .collect();
+            let i = *candidates.choose(&mut thread_rng()).unwrap();
+            *tree = tree.extract(i);
+        }
+        chromosome
+    }
+}
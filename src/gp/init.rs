@@ -0,0 +1,84 @@
+use crate::gp::tree::{Node, Tree};
+use crate::gp::{PrimitiveSet, TerminalKind, Type};
+use rand::prelude::SliceRandom;
+use rand::{thread_rng, Rng};
+use std::sync::Arc;
+
+/// A tree whose every leaf lies at `depth`, as far as the types allow.
+#[must_use]
+pub fn full<V: Clone>(set: &Arc<PrimitiveSet<V>>, depth: usize) -> Tree<V> {
+    let mut nodes = Vec::new();
+    generate(set, set.root, depth, true, &mut nodes);
+    Tree::new(nodes, Arc::clone(set))
+}
+
+/// A tree of irregular shape and depth at most `max_depth`.
+#[must_use]
+pub fn grow<V: Clone>(set: &Arc<PrimitiveSet<V>>, max_depth: usize) -> Tree<V> {
+    let mut nodes = Vec::new();
+    generate(set, set.root, max_depth, false, &mut nodes);
+    Tree::new(nodes, Arc::clone(set))
+}
+
+/// Koza's ramped half-and-half: a depth drawn uniformly in `min_depth..=max_depth`,
+/// then `full` or `grow` with equal probability.
+/// Called once per chromosome from `Problem::genotype`, it yields a population of varied shapes.
+#[must_use]
+pub fn ramped_half_and_half<V: Clone>(
+    set: &Arc<PrimitiveSet<V>>,
+    min_depth: usize,
+    max_depth: usize,
+) -> Tree<V> {
+    let depth = thread_rng().gen_range(min_depth..=max_depth);
+    if thread_rng().gen_bool(0.5) {
+        full(set, depth)
+    } else {
+        grow(set, depth)
+    }
+}
+
+/// Append a random subtree of type `ty` and depth at most `depth` to `nodes`, in prefix order.
+///
+/// # Panics
+/// Will panic if the set has no primitive of type `ty`.
+pub(crate) fn generate<V: Clone>(
+    set: &PrimitiveSet<V>,
+    ty: Type,
+    depth: usize,
+    full: bool,
+    nodes: &mut Vec<Node<V>>,
+) {
+    let functions: Vec<usize> = set.functions_of(ty).collect();
+    let terminals: Vec<usize> = set.terminals_of(ty).collect();
+    assert!(
+        !functions.is_empty() || !terminals.is_empty(),
+        "no primitive of type {ty}"
+    );
+
+    // Functions are used past the depth limit only when the type has no terminal
+    let use_function = if depth == 0 || functions.is_empty() {
+        terminals.is_empty()
+    } else if full || terminals.is_empty() {
+        true
+    } else {
+        thread_rng().gen_range(0..functions.len() + terminals.len()) < functions.len()
+    };
+
+    if use_function {
+        let f = *functions.choose(&mut thread_rng()).unwrap();
+        nodes.push(Node::Function(f));
+        for &arg in &set.functions[f].args {
+            generate(set, arg, depth.saturating_sub(1), full, nodes);
+        }
+    } else {
+        nodes.push(terminal(set, *terminals.choose(&mut thread_rng()).unwrap()));
+    }
+}
+
+/// A node for terminal `t`, drawing its value if it is an ephemeral constant.
+pub(crate) fn terminal<V: Clone>(set: &PrimitiveSet<V>, t: usize) -> Node<V> {
+    match &set.terminals[t].kind {
+        TerminalKind::Ephemeral(generator) => Node::Ephemeral(t, generator()),
+        TerminalKind::Variable(_) | TerminalKind::Constant(_) => Node::Terminal(t),
+    }
+}
@@ -0,0 +1,59 @@
+use crate::chromosome::Chromosome;
+use crate::gp::tree::Tree;
+use crate::problem::Problem;
+use crate::selection::SelectionStrategy;
+use itertools::Itertools;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+use std::cmp::Reverse;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Tournament selection with lexicographic parsimony pressure:
+/// the fittest of `size` random chromosomes wins, and among equally fit ones the smallest,
+/// counted in tree nodes.
+#[derive(Clone, Debug)]
+pub struct ParsimonyTournament<T: Problem> {
+    _problem: PhantomData<T>,
+    size: usize,
+}
+
+impl<T: Problem> ParsimonyTournament<T> {
+    #[must_use]
+    pub fn new(size: usize) -> Self {
+        Self {
+            _problem: PhantomData,
+            size,
+        }
+    }
+}
+
+impl<T: Problem> Default for ParsimonyTournament<T> {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+/// Total number of nodes of the trees of a chromosome.
+pub fn tree_size<V: Clone, T: Problem<Allele = Tree<V>>>(chromosome: &Chromosome<T>) -> usize {
+    chromosome.genes().iter().map(Tree::size).sum()
+}
+
+impl<V, T> SelectionStrategy<T> for ParsimonyTournament<T>
+where
+    V: Clone + Eq + Hash + Display,
+    T: Problem<Allele = Tree<V>>,
+{
+    fn select(&self, population: &[Chromosome<T>], n: usize) -> Vec<Chromosome<T>> {
+        (0..n)
+            .map(|_| {
+                population
+                    .choose_multiple(&mut thread_rng(), self.size)
+                    .max_by_key(|&c| (c.get_fitness(), Reverse(tree_size(c))))
+                    .unwrap()
+                    .clone()
+            })
+            .collect_vec()
+    }
+}
@@ -0,0 +1,66 @@
+use crate::chromosome::Chromosome;
+use crate::gp::init::terminal;
+use crate::gp::tree::{Node, Tree};
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::prelude::SliceRandom;
+use rand::{thread_rng, Rng};
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Replaces every node independently with probability `node_rate` by another primitive
+/// with the same signature: a function with the same return and argument types, or a
+/// terminal of the same type. The shape of the tree is preserved.
+#[derive(Clone, Debug)]
+pub struct PointMutation<T: Problem> {
+    _problem: PhantomData<T>,
+    node_rate: f64,
+}
+
+impl<T: Problem> PointMutation<T> {
+    #[must_use]
+    pub fn new(node_rate: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            node_rate,
+        }
+    }
+}
+
+impl<V, T> MutationStrategy<T> for PointMutation<T>
+where
+    V: Clone + Eq + Hash + Display,
+    T: Problem<Allele = Tree<V>>,
+{
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for tree in chromosome.genes_mut() {
+            let set = tree.primitive_set().clone();
+            let mut nodes = tree.nodes().to_vec();
+
+            for (i, node) in nodes.iter_mut().enumerate() {
+                if !thread_rng().gen_bool(self.node_rate) {
+                    continue;
+                }
+                let ty = tree.type_at(i);
+                *node = match node {
+                    Node::Function(f) => {
+                        let args = &set.functions[*f].args;
+                        let compatible: Vec<usize> = set
+                            .functions_of(ty)
+                            .filter(|&g| set.functions[g].args == *args)
+                            .collect();
+                        Node::Function(*compatible.choose(&mut thread_rng()).unwrap())
+                    }
+                    Node::Terminal(_) | Node::Ephemeral(..) => {
+                        let compatible: Vec<usize> = set.terminals_of(ty).collect();
+                        terminal(&set, *compatible.choose(&mut thread_rng()).unwrap())
+                    }
+                };
+            }
+
+            *tree = Tree::new(nodes, set);
+        }
+        chromosome
+    }
+}
@@ -0,0 +1,87 @@
+use crate::chromosome::Chromosome;
+use crate::crossover::CrossoverStrategy;
+use crate::gp::tree::Tree;
+use crate::gp::Limits;
+use crate::problem::Problem;
+use rand::prelude::SliceRandom;
+use rand::{thread_rng, Rng};
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Swaps a random subtree of each father tree with a subtree of the same type in the matching mother tree.
+///
+/// A child exceeding the `Limits` is replaced by a copy of its parent.
+#[derive(Clone, Debug)]
+pub struct SubtreeCrossover<T: Problem> {
+    _problem: PhantomData<T>,
+    limits: Limits,
+}
+
+impl<T: Problem> SubtreeCrossover<T> {
+    #[must_use]
+    pub fn new(limits: Limits) -> Self {
+        Self {
+            _problem: PhantomData,
+            limits,
+        }
+    }
+}
+
+impl<T: Problem> Default for SubtreeCrossover<T> {
+    fn default() -> Self {
+        Self::new(Limits::default())
+    }
+}
+
+impl<V, T> CrossoverStrategy<T> for SubtreeCrossover<T>
+where
+    V: Clone + Eq + Hash + Display,
+    T: Problem<Allele = Tree<V>>,
+{
+    fn crossover(
+        &self,
+        father: Chromosome<T>,
+        mother: Chromosome<T>,
+    ) -> (Chromosome<T>, Chromosome<T>) {
+        let (father_len, mother_len) = (father.genes().len(), mother.genes().len());
+        let (son, daughter) = father
+            .genes()
+            .iter()
+            .zip(mother.genes())
+            .map(|(a, b)| swap_subtrees(a, b, self.limits))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        let son = son
+            .into_iter()
+            .chain(father.into_genes().into_iter().skip(mother_len));
+        let daughter = daughter
+            .into_iter()
+            .chain(mother.into_genes().into_iter().skip(father_len));
+
+        (
+            Chromosome::new(son.collect()),
+            Chromosome::new(daughter.collect()),
+        )
+    }
+}
+
+fn swap_subtrees<V: Clone>(a: &Tree<V>, b: &Tree<V>, limits: Limits) -> (Tree<V>, Tree<V>) {
+    let i = thread_rng().gen_range(0..a.size());
+    let ty = a.type_at(i);
+    let candidates: Vec<usize> = (0..b.size()).filter(|&j| b.type_at(j) == ty).collect();
+    let Some(&j) = candidates.choose(&mut thread_rng()) else {
+        return (a.clone(), b.clone());
+    };
+
+    let son = a.replace(i, &b.nodes()[b.subtree(j)]);
+    let daughter = b.replace(j, &a.nodes()[a.subtree(i)]);
+    (
+        if son.within(limits) { son } else { a.clone() },
+        if daughter.within(limits) {
+            daughter
+        } else {
+            b.clone()
+        },
+    )
+}
@@ -0,0 +1,57 @@
+use crate::chromosome::Chromosome;
+use crate::gp::init::generate;
+use crate::gp::tree::Tree;
+use crate::gp::Limits;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::fmt::Display;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// Replaces a random subtree of every tree with a new one grown to at most `max_depth`.
+///
+/// A mutant exceeding the `Limits` is discarded and the tree left unchanged.
+#[derive(Clone, Debug)]
+pub struct SubtreeMutation<T: Problem> {
+    _problem: PhantomData<T>,
+    max_depth: usize,
+    limits: Limits,
+}
+
+impl<T: Problem> SubtreeMutation<T> {
+    #[must_use]
+    pub fn new(max_depth: usize, limits: Limits) -> Self {
+        Self {
+            _problem: PhantomData,
+            max_depth,
+            limits,
+        }
+    }
+}
+
+impl<V, T> MutationStrategy<T> for SubtreeMutation<T>
+where
+    V: Clone + Eq + Hash + Display,
+    T: Problem<Allele = Tree<V>>,
+{
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for tree in chromosome.genes_mut() {
+            let i = thread_rng().gen_range(0..tree.size());
+            let mut subtree = Vec::new();
+            generate(
+                tree.primitive_set(),
+                tree.type_at(i),
+                self.max_depth,
+                false,
+                &mut subtree,
+            );
+
+            let mutant = tree.replace(i, &subtree);
+            if mutant.within(self.limits) {
+                *tree = mutant;
+            }
+        }
+        chromosome
+    }
+}
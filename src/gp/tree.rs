@@ -0,0 +1,209 @@
+use crate::gp::{Limits, PrimitiveSet, TerminalKind, Type};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A node of an expression tree, referring to a primitive of the tree's `PrimitiveSet` by index.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Node<V> {
+    Function(usize),
+    Terminal(usize),
+    /// An ephemeral constant terminal, with the value drawn for this node.
+    Ephemeral(usize, V),
+}
+
+/// An expression tree stored in prefix order.
+///
+/// Equality and hashing only consider the nodes, so trees can be used as alleles.
+#[derive(Clone)]
+pub struct Tree<V> {
+    nodes: Vec<Node<V>>,
+    set: Arc<PrimitiveSet<V>>,
+}
+
+impl<V: Clone> Tree<V> {
+    /// # Panics
+    /// Will panic if `nodes` is not a complete tree in prefix order.
+    #[must_use]
+    pub fn new(nodes: Vec<Node<V>>, set: Arc<PrimitiveSet<V>>) -> Self {
+        let tree = Self { nodes, set };
+        assert!(
+            !tree.nodes.is_empty() && tree.subtree(0).end == tree.nodes.len(),
+            "nodes must form a single complete tree"
+        );
+        tree
+    }
+
+    #[must_use]
+    pub fn nodes(&self) -> &[Node<V>] {
+        &self.nodes
+    }
+
+    #[must_use]
+    pub fn primitive_set(&self) -> &Arc<PrimitiveSet<V>> {
+        &self.set
+    }
+
+    /// Number of nodes.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Number of edges on the longest path from the root to a leaf.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        let mut depth = 0;
+        let mut open = Vec::new();
+        for node in &self.nodes {
+            depth = depth.max(open.len());
+            match self.arity(node) {
+                0 => {
+                    while let Some(remaining) = open.last_mut() {
+                        *remaining -= 1;
+                        if *remaining > 0 {
+                            break;
+                        }
+                        open.pop();
+                    }
+                }
+                arity => open.push(arity),
+            }
+        }
+        depth
+    }
+
+    #[must_use]
+    pub fn within(&self, limits: Limits) -> bool {
+        self.size() <= limits.max_size && self.depth() <= limits.max_depth
+    }
+
+    /// Type the node at `index` evaluates to.
+    #[must_use]
+    pub fn type_at(&self, index: usize) -> Type {
+        match &self.nodes[index] {
+            Node::Function(f) => self.set.functions[*f].ty,
+            Node::Terminal(t) | Node::Ephemeral(t, _) => self.set.terminals[*t].ty,
+        }
+    }
+
+    /// Indices of the nodes making up the subtree rooted at `index`.
+    #[must_use]
+    pub fn subtree(&self, index: usize) -> Range<usize> {
+        let mut pending = 1;
+        let mut end = index;
+        while pending > 0 && end < self.nodes.len() {
+            pending += self.arity(&self.nodes[end]);
+            pending -= 1;
+            end += 1;
+        }
+        index..end
+    }
+
+    /// The subtree rooted at `index`, as a tree of its own.
+    #[must_use]
+    pub fn extract(&self, index: usize) -> Self {
+        Self {
+            nodes: self.nodes[self.subtree(index)].to_vec(),
+            set: Arc::clone(&self.set),
+        }
+    }
+
+    /// This tree with the subtree rooted at `index` replaced by `replacement`.
+    #[must_use]
+    pub fn replace(&self, index: usize, replacement: &[Node<V>]) -> Self {
+        let mut nodes = self.nodes.clone();
+        nodes.splice(self.subtree(index), replacement.iter().cloned());
+        Self {
+            nodes,
+            set: Arc::clone(&self.set),
+        }
+    }
+
+    /// Evaluate the tree, binding `Variable(i)` terminals to `inputs[i]`.
+    #[must_use]
+    pub fn eval(&self, inputs: &[V]) -> V {
+        self.eval_from(&mut 0, inputs)
+    }
+
+    fn eval_from(&self, index: &mut usize, inputs: &[V]) -> V {
+        let node = &self.nodes[*index];
+        *index += 1;
+
+        match node {
+            Node::Function(f) => {
+                let function = &self.set.functions[*f];
+                let args: Vec<V> = function
+                    .args
+                    .iter()
+                    .map(|_| self.eval_from(index, inputs))
+                    .collect();
+                (function.eval)(&args)
+            }
+            Node::Terminal(t) => match &self.set.terminals[*t].kind {
+                TerminalKind::Variable(i) => inputs[*i].clone(),
+                TerminalKind::Constant(value) => value.clone(),
+                TerminalKind::Ephemeral(generator) => generator(),
+            },
+            Node::Ephemeral(_, value) => value.clone(),
+        }
+    }
+
+    fn arity(&self, node: &Node<V>) -> usize {
+        match node {
+            Node::Function(f) => self.set.functions[*f].args.len(),
+            Node::Terminal(_) | Node::Ephemeral(..) => 0,
+        }
+    }
+}
+
+impl<V: PartialEq> PartialEq for Tree<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes == other.nodes
+    }
+}
+
+impl<V: Eq> Eq for Tree<V> {}
+
+impl<V: Hash> Hash for Tree<V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.nodes.hash(state);
+    }
+}
+
+impl<V: Clone + Display> Display for Tree<V> {
+    /// Writes the tree as an S-expression, e.g. `(add x (mul x 2))`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut closing = Vec::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            match node {
+                Node::Function(p) => {
+                    write!(f, "({}", self.set.functions[*p].name)?;
+                    closing.push(self.arity(node));
+                    continue;
+                }
+                Node::Terminal(t) => write!(f, "{}", self.set.terminals[*t].name)?,
+                Node::Ephemeral(_, value) => write!(f, "{value}")?,
+            }
+            while let Some(remaining) = closing.last_mut() {
+                *remaining -= 1;
+                if *remaining > 0 {
+                    break;
+                }
+                closing.pop();
+                write!(f, ")")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<V: Clone + Display> Debug for Tree<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Tree({self})")
+    }
+}
@@ -0,0 +1,328 @@
+//! Grammatical evolution: chromosomes of integer codons mapped to programs of a BNF grammar.
+//!
+//! The codons choose, from left to right, which production expands the leftmost non-terminal
+//! of the derivation, and are reused from the start (wrapping) when exhausted.
+//! Since the genotype is a plain vector of integers, all the linear crossovers
+//! and mutations apply unchanged.
+
+use num::cast::AsPrimitive;
+use rand::{thread_rng, Rng};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Symbol {
+    Terminal(String),
+    NonTerminal(String),
+}
+
+/// A context-free grammar. The first rule defines the start symbol.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grammar {
+    start: String,
+    rules: BTreeMap<String, Vec<Vec<Symbol>>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GrammarError {
+    Io(String),
+    /// A line that is neither a rule nor the continuation of one.
+    Syntax {
+        line: usize,
+        message: String,
+    },
+    UndefinedNonTerminal(String),
+    Empty,
+}
+
+impl Display for GrammarError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "cannot read grammar: {error}"),
+            Self::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            Self::UndefinedNonTerminal(name) => write!(f, "<{name}> is used but never defined"),
+            Self::Empty => write!(f, "grammar has no rules"),
+        }
+    }
+}
+
+impl std::error::Error for GrammarError {}
+
+/// Tree of the productions chosen while mapping a chromosome.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Derivation {
+    Terminal(String),
+    NonTerminal {
+        name: String,
+        /// Index of the production chosen among the alternatives of the rule.
+        production: usize,
+        children: Vec<Derivation>,
+    },
+}
+
+impl Derivation {
+    /// The program derived, the concatenation of the terminals.
+    #[must_use]
+    pub fn phenotype(&self) -> String {
+        let mut phenotype = String::new();
+        self.write_terminals(&mut phenotype);
+        phenotype
+    }
+
+    fn write_terminals(&self, out: &mut String) {
+        match self {
+            Self::Terminal(text) => out.push_str(text),
+            Self::NonTerminal { children, .. } => {
+                children.iter().for_each(|child| child.write_terminals(out));
+            }
+        }
+    }
+}
+
+/// Outcome of a successful mapping.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Mapping {
+    pub derivation: Derivation,
+    /// Number of codons read, counting reused ones.
+    pub codons_used: usize,
+    /// Number of times the chromosome was wrapped around.
+    pub wraps: usize,
+}
+
+impl Mapping {
+    #[must_use]
+    pub fn phenotype(&self) -> String {
+        self.derivation.phenotype()
+    }
+}
+
+impl Grammar {
+    /// Nesting depth at which a derivation is abandoned, so self-recursive rules cannot overflow the stack.
+    const MAX_DEPTH: usize = 256;
+
+    /// Parse a grammar in BNF, one rule per line:
+    ///
+    /// ```text
+    /// <expr> ::= <expr> <op> <expr> | "(" <expr> ")" | <var>
+    /// <op>   ::= "+" | "-" | "*"
+    ///          | "/"
+    /// <var>  ::= x | y
+    /// ```
+    ///
+    /// Terminals are either quoted, which allows spaces, or bare words.
+    /// Lines starting with `|` continue the previous rule and lines starting with `#` are ignored.
+    ///
+    /// # Errors
+    /// Returns an error if a line cannot be parsed or a non-terminal is used without being defined.
+    pub fn parse(bnf: &str) -> Result<Self, GrammarError> {
+        let mut grammar = Self::default();
+        let mut current: Option<String> = None;
+
+        for (number, line) in bnf.lines().enumerate().map(|(i, l)| (i + 1, l.trim())) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let syntax = |message: &str| GrammarError::Syntax {
+                line: number,
+                message: message.to_string(),
+            };
+
+            let (name, rhs) = if let Some(rest) = line.strip_prefix('|') {
+                let name = current
+                    .clone()
+                    .ok_or_else(|| syntax("continuation without a rule"))?;
+                (name, rest)
+            } else {
+                let (lhs, rhs) = line
+                    .split_once("::=")
+                    .ok_or_else(|| syntax("expected `<name> ::= ...`"))?;
+                let name = lhs
+                    .trim()
+                    .strip_prefix('<')
+                    .and_then(|lhs| lhs.strip_suffix('>'))
+                    .ok_or_else(|| syntax("left-hand side must be a <non-terminal>"))?
+                    .to_string();
+                if grammar.start.is_empty() {
+                    grammar.start.clone_from(&name);
+                }
+                (name, rhs)
+            };
+
+            let productions = parse_productions(rhs).map_err(|message| syntax(&message))?;
+            grammar
+                .rules
+                .entry(name.clone())
+                .or_default()
+                .extend(productions);
+            current = Some(name);
+        }
+
+        if grammar.rules.is_empty() {
+            return Err(GrammarError::Empty);
+        }
+        for symbol in grammar.rules.values().flatten().flatten() {
+            if let Symbol::NonTerminal(name) = symbol {
+                if !grammar.rules.contains_key(name) {
+                    return Err(GrammarError::UndefinedNonTerminal(name.clone()));
+                }
+            }
+        }
+        Ok(grammar)
+    }
+
+    /// # Errors
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, GrammarError> {
+        let bnf =
+            std::fs::read_to_string(path).map_err(|error| GrammarError::Io(error.to_string()))?;
+        Self::parse(&bnf)
+    }
+
+    #[must_use]
+    pub fn start(&self) -> &str {
+        &self.start
+    }
+
+    #[must_use]
+    pub fn productions(&self, name: &str) -> Option<&[Vec<Symbol>]> {
+        self.rules.get(name).map(Vec::as_slice)
+    }
+
+    /// Map `codons` to a derivation of the start symbol.
+    ///
+    /// Rules with a single production do not consume a codon; otherwise the production chosen
+    /// is the codon modulo the number of alternatives.
+    /// Returns `None` for invalid individuals, whose derivation is still incomplete after
+    /// `max_wraps` wraps; these are usually given the worst fitness.
+    #[must_use]
+    pub fn map<C: AsPrimitive<usize>>(&self, codons: &[C], max_wraps: usize) -> Option<Mapping> {
+        let mut mapper = Mapper {
+            grammar: self,
+            codons,
+            budget: codons.len() * (max_wraps + 1),
+            used: 0,
+        };
+        let derivation = mapper.expand(&self.start, 0)?;
+
+        Some(Mapping {
+            derivation,
+            codons_used: mapper.used,
+            wraps: mapper.used.saturating_sub(1) / codons.len().max(1),
+        })
+    }
+
+    /// Shorthand for the phenotype of `map`.
+    #[must_use]
+    pub fn phenotype<C: AsPrimitive<usize>>(
+        &self,
+        codons: &[C],
+        max_wraps: usize,
+    ) -> Option<String> {
+        self.map(codons, max_wraps)
+            .map(|mapping| mapping.phenotype())
+    }
+}
+
+/// `length` random 8-bit codons, the usual genotype of grammatical evolution.
+#[must_use]
+pub fn genotype(length: usize) -> Vec<u8> {
+    (0..length).map(|_| thread_rng().gen()).collect()
+}
+
+struct Mapper<'a, C> {
+    grammar: &'a Grammar,
+    codons: &'a [C],
+    budget: usize,
+    used: usize,
+}
+
+impl<C: AsPrimitive<usize>> Mapper<'_, C> {
+    fn expand(&mut self, name: &str, depth: usize) -> Option<Derivation> {
+        if depth > Grammar::MAX_DEPTH {
+            return None;
+        }
+        let productions = &self.grammar.rules[name];
+
+        let production = if productions.len() == 1 {
+            0
+        } else {
+            if self.used >= self.budget {
+                return None;
+            }
+            let codon: usize = self.codons[self.used % self.codons.len()].as_();
+            self.used += 1;
+            codon % productions.len()
+        };
+
+        let children = productions[production]
+            .iter()
+            .map(|symbol| match symbol {
+                Symbol::Terminal(text) => Some(Derivation::Terminal(text.clone())),
+                Symbol::NonTerminal(child) => self.expand(child, depth + 1),
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Derivation::NonTerminal {
+            name: name.to_string(),
+            production,
+            children,
+        })
+    }
+}
+
+fn parse_productions(rhs: &str) -> Result<Vec<Vec<Symbol>>, String> {
+    let mut productions = vec![Vec::new()];
+    let mut chars = rhs.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            _ if c.is_whitespace() => {
+                chars.next();
+            }
+            '|' => {
+                chars.next();
+                productions.push(Vec::new());
+            }
+            '<' => {
+                chars.next();
+                let name: String = chars.by_ref().take_while(|&c| c != '>').collect();
+                if name.is_empty() {
+                    return Err("empty non-terminal".to_string());
+                }
+                productions
+                    .last_mut()
+                    .unwrap()
+                    .push(Symbol::NonTerminal(name));
+            }
+            '"' | '\'' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some(q) if q == c => break,
+                        Some(other) => text.push(other),
+                        None => return Err(format!("unterminated {c}quoted{c} terminal")),
+                    }
+                }
+                productions.last_mut().unwrap().push(Symbol::Terminal(text));
+            }
+            _ => {
+                let mut text = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || matches!(c, '|' | '<' | '"' | '\'') {
+                        break;
+                    }
+                    text.push(c);
+                    chars.next();
+                }
+                productions.last_mut().unwrap().push(Symbol::Terminal(text));
+            }
+        }
+    }
+
+    if productions.iter().any(Vec::is_empty) {
+        return Err("empty production, use \"\" for the empty string".to_string());
+    }
+    Ok(productions)
+}
@@ -1,7 +1,22 @@
 extern crate core;
 
+pub mod binary;
+pub mod bitstring;
+pub mod cgp;
 pub mod chromosome;
+pub mod cmaes;
+pub mod constraint;
 pub mod crossover;
+pub mod distance;
+pub mod domain;
 pub mod genetic;
+pub mod genome;
+pub mod gp;
+pub mod grammar;
+pub mod lineage;
+pub mod mutation;
+pub mod permutation;
 pub mod problem;
+pub mod real;
+pub mod report;
 pub mod selection;
@@ -0,0 +1,187 @@
+//! Genealogy of the chromosomes created during a run, recorded by `GeneticAlgorithm::run_with_lineage`.
+//!
+//! Every chromosome created by the engine gets a `Record`: the initial population and random
+//! immigrants have no parents, crossover children list the whole group of parents they were
+//! recombined from, mutants their single pre-mutation parent, and chromosomes truncated to
+//! the maximum length their untruncated self, with the operator `"truncation"`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub id: u64,
+    pub parents: Vec<u64>,
+    /// Operator that produced the chromosome, `None` for the initial population.
+    pub operator: Option<&'static str>,
+    /// Generation the chromosome was created in.
+    pub generation: u32,
+    /// Fitness of its latest evaluation, `None` for intermediate offspring mutated before being evaluated.
+    pub fitness: Option<f64>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lineage {
+    records: BTreeMap<u64, Record>,
+    best: Option<u64>,
+}
+
+impl Lineage {
+    pub(crate) fn record(
+        &mut self,
+        id: u64,
+        parents: Vec<u64>,
+        operator: Option<&'static str>,
+        generation: u32,
+    ) {
+        self.records.insert(
+            id,
+            Record {
+                id,
+                parents,
+                operator,
+                generation,
+                fitness: None,
+            },
+        );
+    }
+
+    pub(crate) fn set_fitness(&mut self, id: u64, fitness: f64) {
+        if let Some(record) = self.records.get_mut(&id) {
+            record.fitness = Some(fitness);
+        }
+    }
+
+    pub(crate) fn set_best(&mut self, id: u64) {
+        self.best = Some(id);
+    }
+
+    #[must_use]
+    pub fn get(&self, id: u64) -> Option<&Record> {
+        self.records.get(&id)
+    }
+
+    /// All records, by increasing id, so parents come before their children.
+    pub fn records(&self) -> impl Iterator<Item = &Record> {
+        self.records.values()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Id of the chromosome returned by the run.
+    #[must_use]
+    pub const fn best(&self) -> Option<u64> {
+        self.best
+    }
+
+    /// The records of `id` and all its ancestors.
+    #[must_use]
+    pub fn ancestry(&self, id: u64) -> Self {
+        let mut ancestors = BTreeSet::new();
+        let mut pending = vec![id];
+        while let Some(id) = pending.pop() {
+            if let Some(record) = self.records.get(&id) {
+                if ancestors.insert(id) {
+                    pending.extend(&record.parents);
+                }
+            }
+        }
+
+        Self {
+            records: ancestors
+                .into_iter()
+                .map(|id| (id, self.records[&id].clone()))
+                .collect(),
+            best: self.best.filter(|best| *best == id),
+        }
+    }
+
+    /// The ancestry of the best chromosome, empty if the run has not finished.
+    #[must_use]
+    pub fn best_ancestry(&self) -> Self {
+        self.best
+            .map_or_else(Self::default, |best| self.ancestry(best))
+    }
+
+    /// A GraphViz digraph with an edge from every parent to its children, labelled with the operator.
+    /// The best chromosome is highlighted.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph lineage {\n    rankdir=TB;\n    node [shape=box];\n");
+        for record in self.records.values() {
+            let fitness = record
+                .fitness
+                .map_or_else(|| "-".to_string(), |f| f.to_string());
+            let style = if Some(record.id) == self.best {
+                ", style=filled, fillcolor=gold"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                dot,
+                "    {} [label=\"#{}\\ngen {}\\nfitness {fitness}\"{style}];",
+                record.id, record.id, record.generation
+            );
+            for parent in record
+                .parents
+                .iter()
+                .filter(|p| self.records.contains_key(p))
+            {
+                let _ = writeln!(
+                    dot,
+                    "    {parent} -> {} [label=\"{}\"];",
+                    record.id,
+                    record.operator.unwrap_or_default()
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The records as a JSON object `{"best": <id or null>, "records": [...]}`.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let records = self
+            .records
+            .values()
+            .map(|r| {
+                format!(
+                    "{{\"id\":{},\"parents\":[{}],\"operator\":{},\"generation\":{},\"fitness\":{}}}",
+                    r.id,
+                    r.parents.iter().map(u64::to_string).collect::<Vec<_>>().join(","),
+                    r.operator
+                        .map_or_else(|| "null".to_string(), |o| format!("\"{}\"", escape(o))),
+                    r.generation,
+                    r.fitness
+                        .filter(|f| f.is_finite())
+                        .map_or_else(|| "null".to_string(), |f| f.to_string()),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let best = self
+            .best
+            .map_or_else(|| "null".to_string(), |b| b.to_string());
+        format!("{{\"best\":{best},\"records\":[{records}]}}")
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// The name of a type without its module path and generic arguments, e.g. `SinglePoint`.
+pub(crate) fn short_type_name<S: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<S>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
@@ -0,0 +1,30 @@
+pub mod bit_flip;
+pub mod creep;
+pub mod deletion;
+pub mod gaussian;
+pub mod insertion;
+pub mod inversion;
+pub mod one_of;
+pub mod random_resetting;
+pub mod scramble;
+pub mod shuffle;
+pub mod swap;
+
+use crate::chromosome::Chromosome;
+use crate::lineage::short_type_name;
+use crate::problem::Problem;
+
+pub enum Mutation {
+    Shuffle,
+    Insertion,
+    Deletion,
+}
+
+pub trait MutationStrategy<T: Problem> {
+    fn mutate(&self, chromosome: Chromosome<T>) -> Chromosome<T>;
+
+    /// Name recorded in the `Lineage` of mutated chromosomes, the type name by default.
+    fn name(&self) -> &'static str {
+        short_type_name::<Self>()
+    }
+}
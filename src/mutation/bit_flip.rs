@@ -0,0 +1,36 @@
+use crate::bitstring::BitString;
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+/// Flips every bit of a packed bit string independently with probability `flip_rate`.
+#[derive(Clone, Debug)]
+pub struct BitFlip<T: Problem<Allele = BitString>> {
+    _problem: PhantomData<T>,
+    flip_rate: f64,
+}
+
+impl<T: Problem<Allele = BitString>> BitFlip<T> {
+    #[must_use]
+    pub fn new(flip_rate: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            flip_rate,
+        }
+    }
+}
+
+impl<T: Problem<Allele = BitString>> MutationStrategy<T> for BitFlip<T> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for bits in chromosome.genes_mut() {
+            for i in 0..bits.len() {
+                if thread_rng().gen_bool(self.flip_rate) {
+                    bits.flip(i);
+                }
+            }
+        }
+        chromosome
+    }
+}
@@ -0,0 +1,44 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+/// Moves every gene with probability `gene_rate` by at most `max_step` within its
+/// `Problem::gene_domain`, in either direction. Genes without a domain are left unchanged.
+#[derive(Clone, Debug)]
+pub struct Creep<T: Problem> {
+    _problem: PhantomData<T>,
+    max_step: i64,
+    gene_rate: f64,
+}
+
+impl<T: Problem> Creep<T> {
+    #[must_use]
+    pub fn new(max_step: i64, gene_rate: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            max_step: max_step.max(1),
+            gene_rate,
+        }
+    }
+}
+
+impl<T: Problem> MutationStrategy<T> for Creep<T> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for (locus, gene) in chromosome.genes_mut().iter_mut().enumerate() {
+            if let Some(domain) = T::gene_domain(locus) {
+                if thread_rng().gen_bool(self.gene_rate) {
+                    let step = thread_rng().gen_range(1..=self.max_step);
+                    let step = if thread_rng().gen_bool(0.5) {
+                        step
+                    } else {
+                        -step
+                    };
+                    *gene = domain.creep(gene, step);
+                }
+            }
+        }
+        chromosome
+    }
+}
@@ -0,0 +1,22 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+/// Removes the gene at a random locus, shrinking the chromosome by one.
+/// Chromosomes with a single gene are left unchanged.
+#[derive(Clone, Default, Debug)]
+pub struct Deletion<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> MutationStrategy<T> for Deletion<T> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        if chromosome.genes().len() > 1 {
+            let locus = thread_rng().gen_range(0..chromosome.genes().len());
+            chromosome.genes_mut().remove(locus);
+        }
+        chromosome
+    }
+}
@@ -0,0 +1,60 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use num::cast::AsPrimitive;
+use rand::{random, thread_rng, Rng};
+use std::f64::consts::TAU;
+use std::marker::PhantomData;
+
+/// Adds normally distributed noise to every numeric gene with probability `gene_rate`.
+///
+/// The standard deviation is `sigma` times the width of the gene's `Problem::gene_bounds`,
+/// or `sigma` itself for unbounded genes; mutated genes are clamped back into their bounds.
+#[derive(Clone, Debug)]
+pub struct Gaussian<T: Problem> {
+    _problem: PhantomData<T>,
+    sigma: f64,
+    gene_rate: f64,
+}
+
+impl<T: Problem> Gaussian<T> {
+    #[must_use]
+    pub fn new(sigma: f64, gene_rate: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            sigma,
+            gene_rate,
+        }
+    }
+}
+
+/// A sample from the standard normal distribution (Box-Muller transform).
+pub(crate) fn standard_normal() -> f64 {
+    let u = 1.0 - random::<f64>();
+    let v = random::<f64>();
+    (-2.0 * u.ln()).sqrt() * (TAU * v).cos()
+}
+
+impl<T: Problem> MutationStrategy<T> for Gaussian<T>
+where
+    T::Allele: AsPrimitive<f64>,
+    f64: AsPrimitive<T::Allele>,
+{
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for (locus, gene) in chromosome.genes_mut().iter_mut().enumerate() {
+            if !thread_rng().gen_bool(self.gene_rate) {
+                continue;
+            }
+
+            let value = gene.as_();
+            *gene = match T::gene_bounds(locus) {
+                Some((lower, upper)) => {
+                    let noise = self.sigma * (upper - lower) * standard_normal();
+                    (value + noise).clamp(lower, upper).as_()
+                }
+                None => (value + self.sigma * standard_normal()).as_(),
+            };
+        }
+        chromosome
+    }
+}
@@ -0,0 +1,23 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::prelude::SliceRandom;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+/// Inserts a new gene at a random locus, growing the chromosome by one.
+/// The allele is taken from a freshly generated genotype.
+#[derive(Clone, Default, Debug)]
+pub struct Insertion<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> MutationStrategy<T> for Insertion<T> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        if let Some(allele) = T::genotype().choose(&mut thread_rng()) {
+            let locus = thread_rng().gen_range(0..=chromosome.genes().len());
+            chromosome.genes_mut().insert(locus, allele.clone());
+        }
+        chromosome
+    }
+}
@@ -0,0 +1,35 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::permutation::Permutation;
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// Reverses the order of the elements in a random segment of every permutation.
+#[derive(Clone, Default, Debug)]
+pub struct Inversion<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem<Allele = Permutation<A>>, A> MutationStrategy<T> for Inversion<T> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for permutation in chromosome.genes_mut() {
+            let segment = segment(permutation.len());
+            permutation.reverse(segment);
+        }
+        chromosome
+    }
+}
+
+/// A uniformly random, possibly empty, segment of `0..length`.
+pub(crate) fn segment(length: usize) -> Range<usize> {
+    let (mut start, mut end) = (
+        thread_rng().gen_range(0..=length),
+        thread_rng().gen_range(0..=length),
+    );
+    if start > end {
+        std::mem::swap(&mut start, &mut end);
+    }
+    start..end
+}
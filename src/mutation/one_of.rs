@@ -0,0 +1,26 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+
+/// Applies one of several mutations, chosen uniformly at random every time.
+pub struct OneOf<T: Problem> {
+    mutations: Vec<Box<dyn MutationStrategy<T>>>,
+}
+
+impl<T: Problem> OneOf<T> {
+    #[must_use]
+    pub fn new(mutations: Vec<Box<dyn MutationStrategy<T>>>) -> Self {
+        Self { mutations }
+    }
+}
+
+impl<T: Problem> MutationStrategy<T> for OneOf<T> {
+    fn mutate(&self, chromosome: Chromosome<T>) -> Chromosome<T> {
+        match self.mutations.choose(&mut thread_rng()) {
+            Some(mutation) => mutation.mutate(chromosome),
+            None => chromosome,
+        }
+    }
+}
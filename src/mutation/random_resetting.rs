@@ -0,0 +1,36 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::{thread_rng, Rng};
+use std::marker::PhantomData;
+
+/// Replaces every gene with probability `gene_rate` by a random value of its `Problem::gene_domain`.
+/// Genes without a domain are left unchanged.
+#[derive(Clone, Debug)]
+pub struct RandomResetting<T: Problem> {
+    _problem: PhantomData<T>,
+    gene_rate: f64,
+}
+
+impl<T: Problem> RandomResetting<T> {
+    #[must_use]
+    pub fn new(gene_rate: f64) -> Self {
+        Self {
+            _problem: PhantomData,
+            gene_rate,
+        }
+    }
+}
+
+impl<T: Problem> MutationStrategy<T> for RandomResetting<T> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for (locus, gene) in chromosome.genes_mut().iter_mut().enumerate() {
+            if let Some(domain) = T::gene_domain(locus) {
+                if thread_rng().gen_bool(self.gene_rate) {
+                    *gene = domain.sample();
+                }
+            }
+        }
+        chromosome
+    }
+}
@@ -0,0 +1,22 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::inversion::segment;
+use crate::mutation::MutationStrategy;
+use crate::permutation::Permutation;
+use crate::problem::Problem;
+use std::marker::PhantomData;
+
+/// Randomly reorders the elements in a random segment of every permutation.
+#[derive(Clone, Default, Debug)]
+pub struct Scramble<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem<Allele = Permutation<A>>, A> MutationStrategy<T> for Scramble<T> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for permutation in chromosome.genes_mut() {
+            let segment = segment(permutation.len());
+            permutation.shuffle(segment);
+        }
+        chromosome
+    }
+}
@@ -0,0 +1,19 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::problem::Problem;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+use std::marker::PhantomData;
+
+/// Randomly reorders all genes of the chromosome.
+#[derive(Clone, Default, Debug)]
+pub struct Shuffle<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem> MutationStrategy<T> for Shuffle<T> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        chromosome.genes_mut().shuffle(&mut thread_rng());
+        chromosome
+    }
+}
@@ -0,0 +1,25 @@
+use crate::chromosome::Chromosome;
+use crate::mutation::MutationStrategy;
+use crate::permutation::Permutation;
+use crate::problem::Problem;
+use rand::seq::index::sample;
+use rand::thread_rng;
+use std::marker::PhantomData;
+
+/// Exchanges the elements at two distinct random positions of every permutation.
+#[derive(Clone, Default, Debug)]
+pub struct Swap<T: Problem> {
+    _problem: PhantomData<T>,
+}
+
+impl<T: Problem<Allele = Permutation<A>>, A> MutationStrategy<T> for Swap<T> {
+    fn mutate(&self, mut chromosome: Chromosome<T>) -> Chromosome<T> {
+        for permutation in chromosome.genes_mut() {
+            if permutation.len() >= 2 {
+                let positions = sample(&mut thread_rng(), permutation.len(), 2);
+                permutation.swap(positions.index(0), positions.index(1));
+            }
+        }
+        chromosome
+    }
+}
@@ -0,0 +1,156 @@
+use crate::chromosome::Chromosome;
+use crate::problem::Problem;
+use itertools::Itertools;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Deref, Range};
+
+/// An ordering of distinct elements, used as a single allele.
+///
+/// It can only be built from distinct elements and its methods only reorder them, so a
+/// chromosome of permutations holds permutations whatever operators it goes through:
+/// operators that copy or replace alleles, like `Uniform` or `SinglePoint`, move whole
+/// permutations between chromosomes. Reordering within the permutations is left to the
+/// operators bound to `Problem<Allele = Permutation<A>>`, the crossovers of `PermutationCrossover`
+/// and the mutations of `PermutationMutation`; they cannot be used with any other allele.
+#[derive(Clone, Default, Eq, PartialEq, Hash, Debug)]
+pub struct Permutation<A = usize> {
+    elements: Vec<A>,
+}
+
+impl Permutation<usize> {
+    /// `0..n` in increasing order.
+    #[must_use]
+    pub fn identity(n: usize) -> Self {
+        Self {
+            elements: (0..n).collect(),
+        }
+    }
+
+    /// A uniformly random permutation of `0..n`.
+    #[must_use]
+    pub fn random(n: usize) -> Self {
+        let mut permutation = Self::identity(n);
+        permutation.shuffle(0..n);
+        permutation
+    }
+}
+
+impl<A> Permutation<A> {
+    /// Wrap elements an operator has only reordered.
+    pub(crate) fn from_reordered(elements: Vec<A>) -> Self
+    where
+        A: Eq + Hash,
+    {
+        debug_assert!(is_permutation(&elements), "elements must be distinct");
+        Self { elements }
+    }
+
+    #[must_use]
+    pub fn as_slice(&self) -> &[A] {
+        &self.elements
+    }
+
+    #[must_use]
+    pub fn into_inner(self) -> Vec<A> {
+        self.elements
+    }
+
+    /// Exchange the elements at positions `i` and `j`.
+    ///
+    /// # Panics
+    /// Will panic if either position is out of bounds.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.elements.swap(i, j);
+    }
+
+    /// Reverse the order of the elements in `range`.
+    ///
+    /// # Panics
+    /// Will panic if `range` is out of bounds.
+    pub fn reverse(&mut self, range: Range<usize>) {
+        self.elements[range].reverse();
+    }
+
+    /// Randomly reorder the elements in `range`.
+    ///
+    /// # Panics
+    /// Will panic if `range` is out of bounds.
+    pub fn shuffle(&mut self, range: Range<usize>) {
+        self.elements[range].shuffle(&mut thread_rng());
+    }
+}
+
+impl<A> Deref for Permutation<A> {
+    type Target = [A];
+
+    fn deref(&self) -> &[A] {
+        &self.elements
+    }
+}
+
+/// Fails, giving the elements back, if they are not distinct.
+impl<A: Eq + Hash> TryFrom<Vec<A>> for Permutation<A> {
+    type Error = Vec<A>;
+
+    fn try_from(elements: Vec<A>) -> Result<Self, Self::Error> {
+        if is_permutation(&elements) {
+            Ok(Self { elements })
+        } else {
+            Err(elements)
+        }
+    }
+}
+
+impl<A: Display> Display for Permutation<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]", self.elements.iter().join(" "))
+    }
+}
+
+/// Permutation-preserving crossovers, see `GeneticBuilder::with_permutation_crossover`.
+pub enum PermutationCrossover {
+    OrderOne,
+    PartiallyMapped,
+    Cycle,
+    EdgeRecombination,
+}
+
+/// Permutation-preserving mutations, see `GeneticBuilder::with_permutation_mutation`.
+pub enum PermutationMutation {
+    Swap,
+    Inversion,
+    Scramble,
+}
+
+/// Whether all genes are distinct.
+#[must_use]
+pub fn is_permutation<A: Eq + Hash>(genes: &[A]) -> bool {
+    genes.iter().all_unique()
+}
+
+/// Recombine the permutations found at the same locus of both parents with `cross`,
+/// which must return two reorderings of its arguments.
+/// Permutations past the end of the shorter parent stay with the child of their parent.
+pub(crate) fn cross_pairwise<T, A, F>(
+    father: Chromosome<T>,
+    mother: Chromosome<T>,
+    cross: F,
+) -> (Chromosome<T>, Chromosome<T>)
+where
+    T: Problem<Allele = Permutation<A>>,
+    A: Eq + Hash,
+    F: Fn(&[A], &[A]) -> (Vec<A>, Vec<A>),
+{
+    let mut son = father.into_genes();
+    let mut daughter = mother.into_genes();
+    for (s, d) in son.iter_mut().zip(daughter.iter_mut()) {
+        let (a, b) = cross(s, d);
+        *s = Permutation::from_reordered(a);
+        *d = Permutation::from_reordered(b);
+    }
+
+    (Chromosome::new(son), Chromosome::new(daughter))
+}
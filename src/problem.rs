@@ -1,10 +1,12 @@
 use crate::chromosome::Chromosome;
+use crate::distance::{Distance, Hamming};
+use crate::domain::Domain;
 use num::cast::AsPrimitive;
+use num::{Bounded, FromPrimitive};
 
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 
-
 pub trait Problem: Eq + PartialEq + Hash + Default + Clone + Debug {
     // TODO: Allow to represent Fitness with a floating point value
     // Currently floats are not supported because they don't implement Ord trait
@@ -18,11 +20,38 @@ pub trait Problem: Eq + PartialEq + Hash + Default + Clone + Debug {
         + Debug
         + Copy
         + Clone
-        + AsPrimitive<f64>;
+        + AsPrimitive<f64>
+        + FromPrimitive
+        + Bounded;
     type Allele: Eq + PartialEq + Hash + Display + Debug + Clone;
 
     fn fitness(&self, chromosome: &Chromosome<Self>) -> Self::Fitness;
+    /// Per-case errors of a chromosome, lower is better.
+    /// Problems scored on many test cases can report them here for lexicase selection.
+    fn case_errors(&self, _chromosome: &Chromosome<Self>) -> Option<Vec<Self::Fitness>> {
+        None
+    }
+    /// Total amount by which a chromosome violates the problem's constraints, 0 when feasible.
+    /// Reported separately from `fitness`, see `crate::constraint` for how it is handled.
+    fn violation(&self, _chromosome: &Chromosome<Self>) -> f64 {
+        0.0
+    }
     fn terminate(&self, population: &[Chromosome<Self>], generation: u32, temperature: f64)
         -> bool;
     fn genotype() -> Vec<Self::Allele>;
+    /// Values the gene at `locus` may take, if restricted.
+    /// Used by `crate::domain` for initialization and validation, and by domain-aware mutations.
+    fn gene_domain(_locus: usize) -> Option<Domain<Self::Allele>> {
+        None
+    }
+    /// Inclusive lower and upper bound of the numeric gene at `locus`, if it has any.
+    /// Operators that create new allele values, like the real-valued crossovers, stay within it.
+    fn gene_bounds(_locus: usize) -> Option<(f64, f64)> {
+        None
+    }
+    /// Distance between two chromosomes used by diversity-preserving operators.
+    /// Defaults to the Hamming distance; see `crate::distance` for other metrics.
+    fn distance(a: &Chromosome<Self>, b: &Chromosome<Self>) -> f64 {
+        Hamming.distance(a, b)
+    }
 }
@@ -0,0 +1,116 @@
+use crate::problem::Problem;
+use num::cast::AsPrimitive;
+use rand::{thread_rng, Rng};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+/// A floating point allele with bitwise equality and hashing.
+///
+/// `f64` is neither `Eq` nor `Hash`, so it cannot be used as `Problem::Allele` directly.
+/// Two `Real`s are equal when they have the same bits, with `0.0` and `-0.0` treated as equal,
+/// which is what the fitness cache and the population sets need.
+/// Ordering agrees with equality: it is `f64::total_cmp`, again with `0.0` and `-0.0` equal.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct Real(pub f64);
+
+impl Real {
+    /// The value with `-0.0` replaced by `0.0`.
+    fn normalized(self) -> f64 {
+        if self.0 == 0.0 {
+            0.0
+        } else {
+            self.0
+        }
+    }
+
+    fn key(self) -> u64 {
+        self.normalized().to_bits()
+    }
+}
+
+impl PartialEq for Real {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Real {}
+
+impl PartialOrd for Real {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Real {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.normalized().total_cmp(&other.normalized())
+    }
+}
+
+impl Hash for Real {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl Display for Real {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<f64> for Real {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Real> for f64 {
+    fn from(value: Real) -> Self {
+        value.0
+    }
+}
+
+impl AsPrimitive<f64> for Real {
+    fn as_(self) -> f64 {
+        self.0
+    }
+}
+
+impl AsPrimitive<Real> for f64 {
+    fn as_(self) -> Real {
+        Real(self)
+    }
+}
+
+/// A vector of reals drawn uniformly within the given per-dimension `(lower, upper)` bounds.
+#[must_use]
+pub fn random_vector(bounds: &[(f64, f64)]) -> Vec<Real> {
+    bounds
+        .iter()
+        .map(|&(lower, upper)| Real(thread_rng().gen_range(lower..=upper)))
+        .collect()
+}
+
+/// A random genotype of `dimensions` reals within `Problem::gene_bounds`.
+///
+/// # Panics
+/// Will panic if the problem does not bound every dimension.
+#[must_use]
+pub fn bounded_genotype<T: Problem<Allele = Real>>(dimensions: usize) -> Vec<Real> {
+    let bounds: Vec<_> = (0..dimensions)
+        .map(|locus| T::gene_bounds(locus).expect("every dimension must be bounded"))
+        .collect();
+
+    random_vector(&bounds)
+}
+
+/// Whether every gene lies within `Problem::gene_bounds`.
+#[must_use]
+pub fn is_within_bounds<T: Problem<Allele = Real>>(genes: &[Real]) -> bool {
+    genes.iter().enumerate().all(|(locus, gene)| {
+        T::gene_bounds(locus).is_none_or(|(lower, upper)| (lower..=upper).contains(&gene.0))
+    })
+}
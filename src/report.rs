@@ -0,0 +1,21 @@
+use crate::chromosome::Chromosome;
+use crate::problem::Problem;
+use std::time::Duration;
+
+/// Outcome of a run, shared by `GeneticAlgorithm` and `CmaEs` so their results can be compared.
+#[derive(Clone, Debug)]
+pub struct Report<T: Problem> {
+    pub best: Chromosome<T>,
+    /// Number of generations completed, summed over restarts.
+    pub generations: u32,
+    /// Number of calls to `Problem::fitness`.
+    pub evaluations: usize,
+    /// Number of restarts, always 0 for the genetic algorithm.
+    pub restarts: u32,
+    /// Best fitness of every generation.
+    pub history: Vec<T::Fitness>,
+    /// Parents the selection strategy could not provide as requested in every generation,
+    /// see `SelectionStrategy::select_with_shortfall`. Empty for `CmaEs`, which does not select.
+    pub shortfalls: Vec<usize>,
+    pub elapsed: Duration,
+}
@@ -1,20 +1,65 @@
 use crate::chromosome::Chromosome;
 use crate::problem::Problem;
+use crate::selection::tournament::UniqueFallback;
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::thread_rng;
 
+pub mod clearing;
 pub mod elitism;
+pub mod feasibility;
+pub mod lexicase;
 pub mod random;
 pub mod roulette;
+pub mod sharing;
+pub mod stochastic_ranking;
 pub mod tournament;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum Selection {
     TournamentWithDuplicates,
-    TournamentWithoutDuplicates,
+    TournamentWithoutDuplicates(UniqueFallback),
     Roulette,
     Elitism,
     Random,
+    Lexicase,
+    EpsilonLexicase,
+    FeasibilityRules,
+    StochasticRanking,
 }
 
 pub trait SelectionStrategy<T: Problem> {
     fn select(&self, population: &[Chromosome<T>], n: usize) -> Vec<Chromosome<T>>;
+
+    /// Like `select`, also returning how many of the `n` parents could not be selected as requested
+    /// and were provided by a fallback instead, see `TournamentWithoutDuplicates`.
+    /// Strategies that always succeed report 0, the default.
+    fn select_with_shortfall(
+        &self,
+        population: &[Chromosome<T>],
+        n: usize,
+    ) -> (Vec<Chromosome<T>>, usize) {
+        (self.select(population, n), 0)
+    }
+}
+
+/// Draw `n` chromosomes with probability proportional to `weights`,
+/// falling back to a uniform draw when every weight is zero.
+pub(crate) fn roulette_weighted<T: Problem>(
+    population: &[Chromosome<T>],
+    weights: &[f64],
+    n: usize,
+) -> Vec<Chromosome<T>> {
+    let weights = if weights.iter().any(|&w| w > 0.0) {
+        weights.to_vec()
+    } else {
+        vec![1.0; weights.len()]
+    };
+    let Ok(distribution) = WeightedIndex::new(weights) else {
+        return Vec::new();
+    };
+
+    (0..n)
+        .map(|_| population[distribution.sample(&mut thread_rng())].clone())
+        .collect()
 }
@@ -0,0 +1,88 @@
+use crate::chromosome::Chromosome;
+use crate::distance::{Distance, ProblemMetric};
+use crate::problem::Problem;
+use crate::selection::{roulette_weighted, SelectionStrategy};
+use itertools::Itertools;
+use num::cast::AsPrimitive;
+
+/// Fitness-proportionate selection after clearing.
+///
+/// Chromosomes are visited from the fittest down; the first `capacity` members of every niche
+/// of size `radius` keep their fitness, the remaining ones are cleared and cannot be selected.
+#[derive(Debug, Clone)]
+pub struct Clearing<T: Problem, D: Distance<T> = ProblemMetric> {
+    _problem: std::marker::PhantomData<T>,
+    radius: f64,
+    capacity: usize,
+    metric: D,
+}
+
+impl<T: Problem> Clearing<T> {
+    #[must_use]
+    pub fn new(radius: f64, capacity: usize) -> Self {
+        Self::with_metric(radius, capacity, ProblemMetric)
+    }
+}
+
+impl<T: Problem, D: Distance<T>> Clearing<T, D> {
+    #[must_use]
+    pub fn with_metric(radius: f64, capacity: usize, metric: D) -> Self {
+        Self {
+            _problem: std::marker::PhantomData,
+            radius,
+            capacity,
+            metric,
+        }
+    }
+
+    fn cleared(&self, population: &[Chromosome<T>]) -> Vec<bool> {
+        let order = (0..population.len())
+            .sorted_by_key(|&i| population[i].get_fitness())
+            .rev()
+            .collect_vec();
+        let mut cleared = vec![false; population.len()];
+
+        for (position, &i) in order.iter().enumerate() {
+            if cleared[i] {
+                continue;
+            }
+
+            let mut members = 1;
+            for &j in &order[position + 1..] {
+                if cleared[j] || self.metric.distance(&population[i], &population[j]) >= self.radius
+                {
+                    continue;
+                }
+                if members < self.capacity {
+                    members += 1;
+                } else {
+                    cleared[j] = true;
+                }
+            }
+        }
+
+        cleared
+    }
+}
+
+impl<T: Problem, D: Distance<T>> SelectionStrategy<T> for Clearing<T, D> {
+    fn select(&self, population: &[Chromosome<T>], n: usize) -> Vec<Chromosome<T>> {
+        let offset = population
+            .iter()
+            .map(|c| c.get_fitness().as_())
+            .fold(0.0, f64::min);
+        let weights = population
+            .iter()
+            .zip(self.cleared(population))
+            .map(|(c, cleared)| {
+                if cleared {
+                    0.0
+                } else {
+                    c.get_fitness().as_() - offset
+                }
+            })
+            .collect_vec();
+
+        roulette_weighted(population, &weights, n)
+    }
+}
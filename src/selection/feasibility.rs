@@ -0,0 +1,39 @@
+use crate::chromosome::Chromosome;
+use crate::problem::Problem;
+use crate::selection::SelectionStrategy;
+use itertools::Itertools;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+use std::cmp::Ordering;
+
+/// Binary tournament decided by Deb's feasibility rules:
+/// a feasible chromosome beats an infeasible one, two feasible chromosomes are compared
+/// on fitness and two infeasible ones on their constraint violation.
+#[derive(Default, Debug, Clone)]
+pub struct FeasibilityTournament<T: Problem> {
+    _problem: std::marker::PhantomData<T>,
+}
+
+/// Order of two chromosomes under Deb's feasibility rules, `Greater` when `a` is better.
+pub fn feasibility_order<T: Problem>(a: &Chromosome<T>, b: &Chromosome<T>) -> Ordering {
+    match (a.is_feasible(), b.is_feasible()) {
+        (true, true) => a.get_fitness().cmp(&b.get_fitness()),
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => b.get_violation().total_cmp(&a.get_violation()),
+    }
+}
+
+impl<T: Problem> SelectionStrategy<T> for FeasibilityTournament<T> {
+    fn select(&self, population: &[Chromosome<T>], n: usize) -> Vec<Chromosome<T>> {
+        (0..n)
+            .map(|_| {
+                population
+                    .choose_multiple(&mut thread_rng(), 2)
+                    .max_by(|a, b| feasibility_order(a, b))
+                    .unwrap()
+                    .clone()
+            })
+            .collect_vec()
+    }
+}
@@ -0,0 +1,119 @@
+use crate::chromosome::Chromosome;
+use crate::problem::Problem;
+use crate::selection::SelectionStrategy;
+use itertools::Itertools;
+use num::cast::AsPrimitive;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+
+/// Lexicase selection over the per-case errors reported by `Problem::case_errors`.
+///
+/// Every parent is chosen by filtering the whole population case by case, in a random order,
+/// keeping only the candidates with the lowest error on the current case.
+/// The epsilon variant also keeps candidates within the median absolute deviation of the best error.
+/// Chromosomes without case errors are compared on their fitness alone.
+#[derive(Default, Debug, Clone)]
+pub struct LexicaseSelection<T: Problem> {
+    _problem: std::marker::PhantomData<T>,
+    epsilon: bool,
+}
+
+impl<T: Problem> LexicaseSelection<T> {
+    #[must_use]
+    pub fn epsilon() -> Self {
+        Self {
+            _problem: std::marker::PhantomData,
+            epsilon: true,
+        }
+    }
+
+    fn case_count(population: &[Chromosome<T>]) -> usize {
+        population
+            .iter()
+            .filter_map(Chromosome::get_case_errors)
+            .map(<[T::Fitness]>::len)
+            .min()
+            .unwrap_or(0)
+    }
+
+    fn error(chromosome: &Chromosome<T>, case: usize) -> f64 {
+        chromosome
+            .get_case_errors()
+            .map_or(f64::INFINITY, |errors| errors[case].as_())
+    }
+
+    fn epsilons(population: &[Chromosome<T>], cases: usize) -> Vec<f64> {
+        (0..cases)
+            .map(|case| {
+                let errors = population
+                    .iter()
+                    .map(|c| Self::error(c, case))
+                    .collect_vec();
+                let center = median(errors.clone());
+                median(errors.into_iter().map(|e| (e - center).abs()).collect())
+            })
+            .collect()
+    }
+
+    fn select_one<'a>(
+        population: &'a [Chromosome<T>],
+        cases: &mut [usize],
+        epsilons: &[f64],
+    ) -> &'a Chromosome<T> {
+        let mut candidates = population.iter().collect_vec();
+        cases.shuffle(&mut thread_rng());
+
+        for &case in cases.iter() {
+            if candidates.len() <= 1 {
+                break;
+            }
+            let best = candidates
+                .iter()
+                .map(|c| Self::error(c, case))
+                .fold(f64::INFINITY, f64::min);
+            let epsilon = epsilons.get(case).copied().unwrap_or(0.0);
+            candidates.retain(|c| Self::error(c, case) <= best + epsilon);
+        }
+
+        if cases.is_empty() {
+            let best = candidates.iter().map(|c| c.get_fitness()).max().unwrap();
+            candidates.retain(|c| c.get_fitness() == best);
+        }
+
+        candidates.choose(&mut thread_rng()).unwrap()
+    }
+}
+
+impl<T: Problem> SelectionStrategy<T> for LexicaseSelection<T> {
+    fn select(&self, population: &[Chromosome<T>], n: usize) -> Vec<Chromosome<T>> {
+        if population.is_empty() {
+            return Vec::new();
+        }
+
+        let mut cases = (0..Self::case_count(population)).collect_vec();
+        let epsilons = if self.epsilon {
+            Self::epsilons(population, cases.len())
+        } else {
+            Vec::new()
+        };
+
+        (0..n)
+            .map(|_| Self::select_one(population, &mut cases, &epsilons).clone())
+            .collect_vec()
+    }
+}
+
+fn median(mut values: Vec<f64>) -> f64 {
+    values.retain(|v| v.is_finite());
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.sort_by(f64::total_cmp);
+
+    let middle = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    }
+}
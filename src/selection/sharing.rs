@@ -0,0 +1,63 @@
+use crate::chromosome::Chromosome;
+use crate::distance::{Distance, ProblemMetric};
+use crate::problem::Problem;
+use crate::selection::{roulette_weighted, SelectionStrategy};
+use itertools::Itertools;
+use num::cast::AsPrimitive;
+
+/// Fitness-proportionate selection on shared fitness.
+///
+/// The fitness of every chromosome is divided by its niche count, the sum of
+/// `1 - (d / radius)^alpha` over all chromosomes closer than `radius`,
+/// so crowded peaks lose selective pressure to sparsely populated ones.
+#[derive(Debug, Clone)]
+pub struct FitnessSharing<T: Problem, D: Distance<T> = ProblemMetric> {
+    _problem: std::marker::PhantomData<T>,
+    radius: f64,
+    alpha: f64,
+    metric: D,
+}
+
+impl<T: Problem> FitnessSharing<T> {
+    #[must_use]
+    pub fn new(radius: f64, alpha: f64) -> Self {
+        Self::with_metric(radius, alpha, ProblemMetric)
+    }
+}
+
+impl<T: Problem, D: Distance<T>> FitnessSharing<T, D> {
+    #[must_use]
+    pub fn with_metric(radius: f64, alpha: f64, metric: D) -> Self {
+        Self {
+            _problem: std::marker::PhantomData,
+            radius,
+            alpha,
+            metric,
+        }
+    }
+
+    fn niche_count(&self, chromosome: &Chromosome<T>, population: &[Chromosome<T>]) -> f64 {
+        population
+            .iter()
+            .map(|other| self.metric.distance(chromosome, other))
+            .filter(|&d| d < self.radius)
+            .map(|d| 1.0 - (d / self.radius).powf(self.alpha))
+            .sum()
+    }
+}
+
+impl<T: Problem, D: Distance<T>> SelectionStrategy<T> for FitnessSharing<T, D> {
+    fn select(&self, population: &[Chromosome<T>], n: usize) -> Vec<Chromosome<T>> {
+        let weights = population
+            .iter()
+            .map(|c| (c.get_fitness().as_(), self.niche_count(c, population)))
+            .collect_vec();
+        let offset = weights.iter().map(|&(f, _)| f).fold(0.0, f64::min);
+        let weights = weights
+            .into_iter()
+            .map(|(f, niche_count)| (f - offset) / niche_count.max(1.0))
+            .collect_vec();
+
+        roulette_weighted(population, &weights, n)
+    }
+}
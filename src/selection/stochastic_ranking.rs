@@ -0,0 +1,63 @@
+use crate::chromosome::Chromosome;
+use crate::problem::Problem;
+use crate::selection::SelectionStrategy;
+use itertools::Itertools;
+use rand::{thread_rng, Rng};
+
+/// Stochastic ranking (Runarsson and Yao) followed by truncation to the `n` best ranked.
+///
+/// The population is ranked with a bubble sort in which adjacent chromosomes are compared on
+/// fitness when both are feasible or with probability `objective_rate`,
+/// and on their constraint violation otherwise.
+#[derive(Debug, Clone)]
+pub struct StochasticRanking<T: Problem> {
+    _problem: std::marker::PhantomData<T>,
+    objective_rate: f64,
+}
+
+impl<T: Problem> Default for StochasticRanking<T> {
+    fn default() -> Self {
+        Self::new(0.45)
+    }
+}
+
+impl<T: Problem> StochasticRanking<T> {
+    #[must_use]
+    pub fn new(objective_rate: f64) -> Self {
+        Self {
+            _problem: std::marker::PhantomData,
+            objective_rate,
+        }
+    }
+
+    /// Whether `a` should be ranked behind `b`.
+    fn should_swap(&self, a: &Chromosome<T>, b: &Chromosome<T>) -> bool {
+        let both_feasible = a.is_feasible() && b.is_feasible();
+        if both_feasible || thread_rng().gen_bool(self.objective_rate) {
+            a.get_fitness() < b.get_fitness()
+        } else {
+            a.get_violation() > b.get_violation()
+        }
+    }
+}
+
+impl<T: Problem> SelectionStrategy<T> for StochasticRanking<T> {
+    fn select(&self, population: &[Chromosome<T>], n: usize) -> Vec<Chromosome<T>> {
+        let mut ranking = population.iter().collect_vec();
+
+        for _ in 0..ranking.len() {
+            let mut swapped = false;
+            for j in 0..ranking.len().saturating_sub(1) {
+                if self.should_swap(ranking[j], ranking[j + 1]) {
+                    ranking.swap(j, j + 1);
+                    swapped = true;
+                }
+            }
+            if !swapped {
+                break;
+            }
+        }
+
+        ranking.into_iter().take(n).cloned().collect_vec()
+    }
+}
@@ -25,25 +25,102 @@ impl<T: Problem> SelectionStrategy<T> for TournamentWithDuplicates<T> {
     }
 }
 
+/// What `TournamentWithoutDuplicates` does when it cannot find `n` distinct winners.
+#[derive(Default, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum UniqueFallback {
+    /// Fill the missing slots with (possibly repeated) tournament winners.
+    #[default]
+    AllowDuplicates,
+    /// Fill the missing slots with freshly generated, unevaluated chromosomes.
+    /// `GeneticAlgorithm` evaluates them before recombination.
+    RandomImmigrants,
+    /// Return only the distinct winners that were found.
+    ReturnFewer,
+}
+
+/// Outcome of a unique tournament selection.
+#[derive(Debug, Clone)]
+pub struct UniqueSelection<T: Problem> {
+    pub selected: Vec<Chromosome<T>>,
+    /// Number of requested parents that could not be filled with distinct winners.
+    /// When non-zero, `fallback` has been applied to these slots.
+    pub shortfall: usize,
+    pub fallback: UniqueFallback,
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct TournamentWithoutDuplicates<T: Problem> {
     _problem: std::marker::PhantomData<T>,
+    fallback: UniqueFallback,
 }
 
-impl<T: Problem> SelectionStrategy<T> for TournamentWithoutDuplicates<T> {
-    fn select(&self, population: &[Chromosome<T>], n: usize) -> Vec<Chromosome<T>> {
-        let mut selected = HashSet::new();
+impl<T: Problem> TournamentWithoutDuplicates<T> {
+    /// Number of tournaments held per requested parent before giving up on finding distinct winners.
+    const ATTEMPTS_PER_PARENT: usize = 16;
+
+    #[must_use]
+    pub fn new(fallback: UniqueFallback) -> Self {
+        Self {
+            _problem: std::marker::PhantomData,
+            fallback,
+        }
+    }
+
+    /// Select up to `n` distinct tournament winners.
+    ///
+    /// Unlike `select`, this reports how many parents had to be provided by the fallback,
+    /// which happens once the population has converged to fewer than `n` distinct chromosomes
+    /// or when the tournaments keep producing winners that were already selected.
+    pub fn select_unique(&self, population: &[Chromosome<T>], n: usize) -> UniqueSelection<T> {
+        let distinct = population.iter().collect::<HashSet<_>>().len();
+        let target = n.min(distinct);
+
+        let mut selected = HashSet::with_capacity(target);
+        let mut attempts = target * Self::ATTEMPTS_PER_PARENT;
+        while selected.len() < target && attempts > 0 {
+            selected.insert(Self::tournament(population).clone());
+            attempts -= 1;
+        }
+
+        let mut selected = selected.into_iter().collect_vec();
+        let shortfall = n - selected.len();
 
-        while selected.len() < n {
-            let winner = population
-                .choose_multiple(&mut thread_rng(), 2)
-                .max_by_key(|&c| c.get_fitness())
-                .unwrap()
-                .clone();
+        match self.fallback {
+            UniqueFallback::AllowDuplicates if !population.is_empty() => {
+                selected.extend((0..shortfall).map(|_| Self::tournament(population).clone()));
+            }
+            UniqueFallback::RandomImmigrants => {
+                selected.extend((0..shortfall).map(|_| Chromosome::new(T::genotype())));
+            }
+            UniqueFallback::AllowDuplicates | UniqueFallback::ReturnFewer => {}
+        }
 
-            selected.insert(winner);
+        UniqueSelection {
+            selected,
+            shortfall,
+            fallback: self.fallback,
         }
+    }
+
+    fn tournament(population: &[Chromosome<T>]) -> &Chromosome<T> {
+        population
+            .choose_multiple(&mut thread_rng(), 2)
+            .max_by_key(|&c| c.get_fitness())
+            .unwrap()
+    }
+}
+
+impl<T: Problem> SelectionStrategy<T> for TournamentWithoutDuplicates<T> {
+    fn select(&self, population: &[Chromosome<T>], n: usize) -> Vec<Chromosome<T>> {
+        self.select_unique(population, n).selected
+    }
 
-        selected.into_iter().collect_vec()
+    fn select_with_shortfall(
+        &self,
+        population: &[Chromosome<T>],
+        n: usize,
+    ) -> (Vec<Chromosome<T>>, usize) {
+        let selection = self.select_unique(population, n);
+        (selection.selected, selection.shortfall)
     }
 }